@@ -0,0 +1,160 @@
+use std::collections::{BTreeSet, HashSet};
+
+use serde::{Deserialize, Serialize};
+use time::{OffsetDateTime, serde::rfc3339};
+
+use super::{ApiClient, ApiError, AuthenticatedVRC};
+use crate::{
+	model::GroupAuditLog,
+	query::{GroupAuditLogs, Pagination},
+};
+
+/// How far a group's audit log has been handled.
+///
+/// Can be persisted and restored, so that a restarted process continues from
+/// where it left off without duplicates or gaps.
+#[derive(
+	Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize, Serialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupAuditLogCursor {
+	/// When the newest handled entry was created,
+	/// `None` if nothing has been handled yet
+	#[serde(default, with = "rfc3339::option")]
+	pub latest: Option<OffsetDateTime>,
+	/// The IDs of the handled entries that were created at [`Self::latest`],
+	/// as multiple entries can share the same time stamp
+	#[serde(default)]
+	pub latest_ids: BTreeSet<String>,
+}
+
+impl GroupAuditLogCursor {
+	/// If the entry hasn't been handled yet
+	#[must_use]
+	pub fn is_new(&self, log: &GroupAuditLog) -> bool {
+		self.latest.is_none_or(|latest| {
+			log.created_at > latest
+				|| (log.created_at == latest && !self.latest_ids.contains(&log.id))
+		})
+	}
+
+	/// Marks the entry as handled
+	pub fn advance(&mut self, log: &GroupAuditLog) {
+		match self.latest {
+			Some(latest) if log.created_at < latest => {}
+			Some(latest) if log.created_at == latest => {
+				self.latest_ids.insert(log.id.clone());
+			}
+			_ => {
+				self.latest = Some(log.created_at);
+				self.latest_ids = BTreeSet::from([log.id.clone()]);
+			}
+		}
+	}
+}
+
+/// Polls a group's audit log for entries that haven't been seen yet
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct GroupAuditLogFollower {
+	/// The ID of the group to follow the audit log of
+	pub group_id: crate::id::Group,
+	/// How far the audit log has been handled
+	pub cursor: GroupAuditLogCursor,
+	/// How many entries to request per page
+	pub page_size: u8,
+}
+
+impl GroupAuditLogFollower {
+	/// Creates a follower that continues from the cursor,
+	/// use the default cursor to start from the beginning of the audit log
+	#[must_use]
+	pub const fn new(
+		group_id: crate::id::Group, cursor: GroupAuditLogCursor,
+	) -> Self {
+		Self { group_id, cursor, page_size: 100 }
+	}
+
+	/// Gets the entries that are newer than the cursor, oldest first,
+	/// and advances the cursor past them.
+	///
+	/// Pages through the audit log until an already handled entry is reached.
+	/// The cursor is only advanced if all the pages were fetched successfully,
+	/// so an error can just be retried later.
+	///
+	/// # Errors
+	///
+	/// If fetching a page of the audit log fails
+	pub async fn poll(
+		&mut self, api_client: &AuthenticatedVRC,
+	) -> Result<Vec<GroupAuditLog>, ApiError> {
+		let mut new_logs = Vec::new();
+		let mut seen_ids = HashSet::new();
+		let mut offset = 0;
+
+		loop {
+			let query = GroupAuditLogs {
+				id: self.group_id.clone(),
+				pagination: Pagination { limit: self.page_size, offset },
+			};
+			let page = api_client.query(query).await?;
+			let page_len = page.results.len();
+
+			let mut reached_handled = false;
+			for log in page.results {
+				if !self.cursor.is_new(&log) {
+					reached_handled = true;
+				} else if seen_ids.insert(log.id.clone()) {
+					new_logs.push(log);
+				}
+			}
+
+			if reached_handled || !page.has_next || page_len == 0 {
+				break;
+			}
+			offset += u32::try_from(page_len).unwrap_or(u32::MAX);
+		}
+
+		new_logs.sort_by_key(|log| log.created_at);
+		for log in &new_logs {
+			self.cursor.advance(log);
+		}
+
+		Ok(new_logs)
+	}
+}
+
+#[cfg(test)]
+#[test]
+fn cursor_advancing() {
+	let log = |id: &str, created_at: &str| -> GroupAuditLog {
+		serde_json::from_value(serde_json::json!({
+			"id": id,
+			"created_at": created_at,
+			"groupId": "grp_93451756-8327-4ecc-b978-3e60aa9f64a9",
+			"actorId": "usr_c1644b5b-3ca4-45b4-97c6-a2a0de70d469",
+			"eventType": "group.member.join",
+			"description": "",
+			"data": {},
+		}))
+		.unwrap()
+	};
+	let first = log("gaud_1", "2024-01-01T12:00:00.000Z");
+	let same_time = log("gaud_2", "2024-01-01T12:00:00.000Z");
+	let older = log("gaud_0", "2023-01-01T12:00:00.000Z");
+	let newer = log("gaud_3", "2024-01-02T12:00:00.000Z");
+
+	let mut cursor = GroupAuditLogCursor::default();
+	assert!(cursor.is_new(&older));
+	cursor.advance(&first);
+	assert!(!cursor.is_new(&first));
+	assert!(!cursor.is_new(&older));
+	assert!(cursor.is_new(&same_time));
+	assert!(cursor.is_new(&newer));
+
+	cursor.advance(&same_time);
+	let cursor: GroupAuditLogCursor =
+		serde_json::from_str(&serde_json::to_string(&cursor).unwrap()).unwrap();
+	assert!(!cursor.is_new(&first));
+	assert!(!cursor.is_new(&same_time));
+	assert!(cursor.is_new(&newer));
+}
@@ -0,0 +1,359 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use time::{
+	OffsetDateTime,
+	format_description::well_known::Rfc3339,
+	serde::rfc3339,
+};
+
+use super::{ApiClient, ApiError, AuthenticatedVRC};
+use crate::{
+	model::FullGroupMember,
+	query::{
+		GroupBan,
+		GroupBans,
+		GroupMemberUpdate,
+		GroupUnban,
+		Pagination,
+		UpdateGroupMember,
+	},
+};
+
+const CSV_HEADER: [&str; 3] = ["userId", "bannedAt", "notes"];
+
+/// A banned user in a [`BanList`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BanListEntry {
+	/// The ID of the banned user
+	pub user_id: crate::id::User,
+	/// When the user was banned
+	#[serde(default, with = "rfc3339::option")]
+	pub banned_at: Option<OffsetDateTime>,
+	/// The manager notes about the user
+	#[serde(default)]
+	pub notes: String,
+}
+
+impl From<&FullGroupMember> for BanListEntry {
+	fn from(member: &FullGroupMember) -> Self {
+		Self {
+			user_id: member.base.user_id.clone(),
+			banned_at: member.banned_at,
+			notes: member.manager_notes.clone().unwrap_or_default(),
+		}
+	}
+}
+
+/// A portable list of the bans of a group.
+///
+/// Serializes as JSON with serde,
+/// or as CSV with the [`to_csv`](Self::to_csv) and
+/// [`from_csv`](Self::from_csv) methods.
+#[derive(
+	Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize, Serialize,
+)]
+#[serde(transparent)]
+pub struct BanList(pub Vec<BanListEntry>);
+
+impl BanList {
+	/// Writes the ban list as CSV with a `userId,bannedAt,notes` header
+	#[must_use]
+	pub fn to_csv(&self) -> String {
+		let mut csv = CSV_HEADER.join(",");
+		csv.push('\n');
+
+		for entry in &self.0 {
+			csv.push_str(entry.user_id.as_ref());
+			csv.push(',');
+			if let Some(banned_at) =
+				entry.banned_at.and_then(|banned_at| banned_at.format(&Rfc3339).ok())
+			{
+				csv.push_str(&banned_at);
+			}
+			csv.push_str(",\"");
+			csv.push_str(&entry.notes.replace('"', "\"\""));
+			csv.push_str("\"\n");
+		}
+
+		csv
+	}
+
+	/// Reads a ban list from CSV, like the one written by
+	/// [`to_csv`](Self::to_csv)
+	///
+	/// # Errors
+	///
+	/// If the CSV is malformed or has invalid user IDs or timestamps
+	pub fn from_csv(csv: &str) -> Result<Self, &'static str> {
+		let mut entries = Vec::new();
+
+		for record in parse_csv(csv)? {
+			let mut fields = record.into_iter();
+			let user_id = fields.next().unwrap_or_default();
+			if user_id.is_empty() || user_id == CSV_HEADER[0] {
+				continue;
+			}
+
+			let banned_at = match fields.next() {
+				Some(banned_at) if !banned_at.is_empty() => Some(
+					OffsetDateTime::parse(&banned_at, &Rfc3339)
+						.map_err(|_| "Ban time should be an RFC 3339 timestamp")?,
+				),
+				_ => None,
+			};
+
+			entries.push(BanListEntry {
+				user_id: user_id.parse()?,
+				banned_at,
+				notes: fields.next().unwrap_or_default(),
+			});
+		}
+
+		Ok(Self(entries))
+	}
+
+	/// The bans that are in this ban list but not in the `current` bans.
+	///
+	/// Only adds bans, so syncing a shared ban list into a group keeps the
+	/// group's own bans. Use [`diff_with_unbans`](Self::diff_with_unbans) to
+	/// also unban the users that aren't in this ban list.
+	///
+	/// This is also the dry run of [`AuthenticatedVRC::apply_ban_list_changes`].
+	#[must_use]
+	pub fn diff(&self, current: &Self) -> Vec<BanListChange> {
+		let banned: HashSet<&crate::id::User> =
+			current.0.iter().map(|entry| &entry.user_id).collect();
+
+		self
+			.0
+			.iter()
+			.filter(|entry| !banned.contains(&entry.user_id))
+			.cloned()
+			.map(BanListChange::Ban)
+			.collect()
+	}
+
+	/// The changes that would make the `current` bans match this ban list
+	/// exactly, unbanning the users that aren't in this ban list
+	#[must_use]
+	pub fn diff_with_unbans(&self, current: &Self) -> Vec<BanListChange> {
+		let wanted: HashSet<&crate::id::User> =
+			self.0.iter().map(|entry| &entry.user_id).collect();
+
+		let unbans = current
+			.0
+			.iter()
+			.filter(|entry| !wanted.contains(&entry.user_id))
+			.cloned()
+			.map(BanListChange::Unban);
+
+		self.diff(current).into_iter().chain(unbans).collect()
+	}
+}
+
+/// A change to the bans of a group
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BanListChange {
+	/// The user should be banned, and the notes added if there are any
+	Ban(BanListEntry),
+	/// The user should be unbanned
+	Unban(BanListEntry),
+}
+
+impl BanListChange {
+	/// The ID of the user that the change is about
+	#[must_use]
+	pub const fn user_id(&self) -> &crate::id::User {
+		match self {
+			Self::Ban(entry) | Self::Unban(entry) => &entry.user_id,
+		}
+	}
+}
+
+impl std::fmt::Display for BanListChange {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self {
+			Self::Ban(entry) => write!(f, "ban {}", entry.user_id),
+			Self::Unban(entry) => write!(f, "unban {}", entry.user_id),
+		}
+	}
+}
+
+impl AuthenticatedVRC {
+	/// Gets all the bans of a group, going through all the pages
+	///
+	/// # Errors
+	///
+	/// If fetching a page of the bans fails
+	pub async fn export_group_bans(
+		&self, group_id: &crate::id::Group,
+	) -> Result<BanList, ApiError> {
+		const PAGE_SIZE: u8 = 100;
+		let mut entries = Vec::new();
+
+		loop {
+			let query = GroupBans {
+				id: group_id.clone(),
+				pagination: Pagination {
+					limit: PAGE_SIZE,
+					offset: u32::try_from(entries.len()).unwrap_or(u32::MAX),
+				},
+			};
+			let page = self.query(query).await?;
+			let page_len = page.len();
+			entries.extend(page.iter().map(BanListEntry::from));
+
+			if page_len < usize::from(PAGE_SIZE) {
+				break;
+			}
+		}
+
+		Ok(BanList(entries))
+	}
+
+	/// Makes the changes to the bans of a group, one after another.
+	///
+	/// Every request waits for the client's rate limiter,
+	/// so large lists of changes take a while.
+	///
+	/// Returns the outcome for each change in the same order as they were
+	/// given. A failure for one change doesn't stop the rest from being made.
+	pub async fn apply_ban_list_changes(
+		&self, group_id: &crate::id::Group,
+		changes: impl IntoIterator<Item = BanListChange> + Send,
+	) -> Vec<(BanListChange, Result<(), ApiError>)> {
+		let mut outcomes = Vec::new();
+
+		for change in changes {
+			let outcome = self.apply_ban_list_change(group_id, &change).await;
+			outcomes.push((change, outcome));
+		}
+
+		outcomes
+	}
+
+	async fn apply_ban_list_change(
+		&self, group_id: &crate::id::Group, change: &BanListChange,
+	) -> Result<(), ApiError> {
+		match change {
+			BanListChange::Ban(entry) => {
+				let query = GroupBan {
+					group_id: group_id.clone(),
+					user_id: entry.user_id.clone(),
+				};
+				self.query(query).await?;
+
+				if !entry.notes.is_empty() {
+					let query = UpdateGroupMember {
+						group_id: group_id.clone(),
+						user_id: entry.user_id.clone(),
+						update: GroupMemberUpdate {
+							manager_notes: Some(entry.notes.clone()),
+							..GroupMemberUpdate::default()
+						},
+					};
+					self.query(query).await?;
+				}
+			}
+			BanListChange::Unban(entry) => {
+				let query = GroupUnban {
+					group_id: group_id.clone(),
+					user_id: entry.user_id.clone(),
+				};
+				self.query(query).await?;
+			}
+		}
+
+		Ok(())
+	}
+}
+
+/// Splits CSV into records of fields, handling quoted fields
+fn parse_csv(csv: &str) -> Result<Vec<Vec<String>>, &'static str> {
+	let mut records = Vec::new();
+	let mut record = Vec::new();
+	let mut field = String::new();
+	let mut in_quotes = false;
+	let mut chars = csv.chars().peekable();
+
+	while let Some(c) = chars.next() {
+		if in_quotes {
+			match c {
+				'"' if chars.peek() == Some(&'"') => {
+					chars.next();
+					field.push('"');
+				}
+				'"' => in_quotes = false,
+				_ => field.push(c),
+			}
+			continue;
+		}
+
+		match c {
+			'"' if field.is_empty() => in_quotes = true,
+			',' => record.push(std::mem::take(&mut field)),
+			'\r' => {}
+			'\n' => {
+				record.push(std::mem::take(&mut field));
+				records.push(std::mem::take(&mut record));
+			}
+			_ => field.push(c),
+		}
+	}
+
+	if in_quotes {
+		return Err("CSV has a quoted field that isn't closed");
+	}
+	if !field.is_empty() || !record.is_empty() {
+		record.push(field);
+		records.push(record);
+	}
+
+	Ok(records)
+}
+
+#[cfg(test)]
+#[test]
+fn ban_list_csv_and_diff() {
+	let shared = BanList(vec![
+		BanListEntry {
+			user_id: "usr_c1644b5b-3ca4-45b4-97c6-a2a0de70d469".parse().unwrap(),
+			banned_at: Some(time::macros::datetime!(2024-01-01 12:00 UTC)),
+			notes: "Said \"hi\", then crashed,\nthe instance".to_owned(),
+		},
+		BanListEntry {
+			user_id: "usr_93451756-8327-4ecc-b978-3e60aa9f64a9".parse().unwrap(),
+			banned_at: None,
+			notes: String::new(),
+		},
+	]);
+	assert_eq!(BanList::from_csv(&shared.to_csv()).unwrap(), shared);
+
+	let current = BanList::from_csv(concat!(
+		"userId,bannedAt,notes\r\n",
+		"usr_93451756-8327-4ecc-b978-3e60aa9f64a9,,\r\n",
+		"usr_0e4d1b1c-96ef-4f62-b3b4-1a6fd7c6bfc6,,\"\"\r\n",
+	))
+	.unwrap();
+	let changes = shared.diff(&current);
+	assert_eq!(
+		changes.iter().map(ToString::to_string).collect::<Vec<_>>(),
+		["ban usr_c1644b5b-3ca4-45b4-97c6-a2a0de70d469"]
+	);
+	let changes = shared.diff_with_unbans(&current);
+	assert_eq!(
+		changes.iter().map(ToString::to_string).collect::<Vec<_>>(),
+		[
+			"ban usr_c1644b5b-3ca4-45b4-97c6-a2a0de70d469",
+			"unban usr_0e4d1b1c-96ef-4f62-b3b4-1a6fd7c6bfc6",
+		]
+	);
+
+	assert!(
+		BanList::from_csv("usr_93451756-8327-4ecc-b978-3e60aa9f64a9,,\"").is_err()
+	);
+	assert!(BanList::from_csv("not-an-id,,").is_err());
+}
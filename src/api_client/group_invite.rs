@@ -0,0 +1,73 @@
+use super::{ApiClient, ApiError, AuthenticatedVRC};
+use crate::{
+	model::GroupMembershipStatus,
+	query::{GroupMember, InviteUserToGroup},
+};
+
+/// What happened when trying to invite a user to a group
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GroupInviteOutcome {
+	/// The user was invited to the group
+	Invited,
+	/// The user is already a member of the group
+	AlreadyMember,
+	/// The user is banned from the group
+	Banned,
+	/// The user has already been invited to the group
+	InvitePending,
+}
+
+impl AuthenticatedVRC {
+	/// Invites multiple users to a group, one after another.
+	///
+	/// The membership of each user is checked before inviting them,
+	/// so that members, banned users and already invited users are skipped.
+	/// Every request waits for the client's rate limiter,
+	/// so large lists of users take a while.
+	///
+	/// Returns the outcome for each user in the same order as they were given.
+	/// A failure for one user doesn't stop the rest from being invited.
+	pub async fn invite_users_to_group(
+		&self, group_id: &crate::id::Group,
+		user_ids: impl IntoIterator<Item = crate::id::User> + Send,
+	) -> Vec<(crate::id::User, Result<GroupInviteOutcome, ApiError>)> {
+		let mut outcomes = Vec::new();
+
+		for user_id in user_ids {
+			let outcome = self.invite_user_to_group(group_id, &user_id).await;
+			outcomes.push((user_id, outcome));
+		}
+
+		outcomes
+	}
+
+	async fn invite_user_to_group(
+		&self, group_id: &crate::id::Group, user_id: &crate::id::User,
+	) -> Result<GroupInviteOutcome, ApiError> {
+		let query =
+			GroupMember { group_id: group_id.clone(), user_id: user_id.clone() };
+		let member = self.query(query).await?;
+
+		match member.map(|member| member.base.membership_status) {
+			Some(GroupMembershipStatus::Member) => {
+				return Ok(GroupInviteOutcome::AlreadyMember);
+			}
+			Some(GroupMembershipStatus::Banned) => {
+				return Ok(GroupInviteOutcome::Banned);
+			}
+			Some(GroupMembershipStatus::Invited) => {
+				return Ok(GroupInviteOutcome::InvitePending);
+			}
+			_ => {}
+		}
+
+		let query = InviteUserToGroup {
+			group_id: group_id.clone(),
+			user_id: user_id.clone(),
+			confirm_override_block: false,
+		};
+		self.query(query).await?;
+
+		Ok(GroupInviteOutcome::Invited)
+	}
+}
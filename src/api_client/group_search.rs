@@ -0,0 +1,40 @@
+use super::{ApiClient, ApiError, AuthenticatedVRC};
+use crate::{
+	model::{GroupCode, LimitedGroup},
+	query::{Pagination, SearchGroups},
+};
+
+impl AuthenticatedVRC {
+	/// Finds a group by its code, going through all the pages of the search
+	/// results.
+	///
+	/// Short codes are shared by many groups, so the group isn't necessarily
+	/// on the first page of the search results.
+	///
+	/// # Errors
+	///
+	/// If fetching a page of the search results fails
+	pub async fn find_group_by_code(
+		&self, code: &GroupCode,
+	) -> Result<Option<LimitedGroup>, ApiError> {
+		const PAGE_SIZE: u8 = 100;
+		let mut offset = 0;
+
+		loop {
+			let query = SearchGroups {
+				query: code.to_string(),
+				pagination: Pagination { limit: PAGE_SIZE, offset },
+			};
+			let page = self.query(query).await?;
+			let page_len = page.len();
+
+			if let Some(group) = code.find(page) {
+				return Ok(Some(group));
+			}
+			if page_len < usize::from(PAGE_SIZE) {
+				return Ok(None);
+			}
+			offset += u32::try_from(page_len).unwrap_or(u32::MAX);
+		}
+	}
+}
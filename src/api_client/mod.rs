@@ -17,6 +17,11 @@
 //!
 //! > Requires the `Authorization` header in addition to the rate limiting.
 
+mod group_audit;
+mod group_bans;
+mod group_invite;
+mod group_search;
+
 use std::num::NonZeroU32;
 
 use governor::{
@@ -32,6 +37,7 @@ use racal::{FromApiState, Queryable};
 use reqwest::{Client, RequestBuilder, Response, header::HeaderMap};
 use serde::de::DeserializeOwned;
 
+pub use self::{group_audit::*, group_bans::*, group_invite::*};
 use crate::{
 	model::{
 		LoginResponse,
@@ -0,0 +1,242 @@
+//! Lossless capturing of fields that the models don't (yet) know about.
+//!
+//! `VRChat` adds new fields to its API responses all the time.
+//! Every model has an `extra` field for the fields that weren't recognized
+//! when deserializing. With the `extra` feature enabled they are kept there
+//! and serialized back as-is, otherwise they are ignored and `extra` stays
+//! empty. The field exists either way, so that enabling the feature doesn't
+//! break code that builds the models.
+//!
+//! Building blocks that are flattened into other models next to other
+//! building blocks, like [`WorldData`](crate::model::WorldData), don't have
+//! their own `extra` field, and their unknown fields are instead captured by
+//! the model that contains them. The ones that are always flattened last, like
+//! [`AccountData`](crate::model::AccountData), do have one.
+//!
+//! With the feature enabled, all the unknown keys are additionally recorded
+//! globally, so that it's easy to spot when the API has drifted from the
+//! models:
+//!
+//! ```
+//! # #[cfg(feature = "extra")] {
+//! let _status: vrc::model::SecondFactorVerificationStatus =
+//! 	serde_json::from_str(r#"{"verified":true,"brandNewField":1}"#).unwrap();
+//!
+//! assert!(vrc::extra::unknown_keys().iter().any(|unknown| {
+//! 	unknown.model.ends_with("SecondFactorVerificationStatus")
+//! 		&& unknown.key == "brandNewField"
+//! }));
+//! # }
+//! ```
+
+#[cfg(feature = "extra")]
+use std::{
+	collections::BTreeSet,
+	sync::{Mutex, PoisonError},
+};
+use std::{
+	marker::PhantomData,
+	ops::{Deref, DerefMut},
+};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+
+#[cfg(feature = "extra")]
+static UNKNOWN_KEYS: Mutex<BTreeSet<UnknownKey>> = Mutex::new(BTreeSet::new());
+
+/// A key that wasn't recognized by a model when deserializing
+#[cfg(feature = "extra")]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnknownKey {
+	/// The model that didn't recognize the key, as given by
+	/// [`std::any::type_name`]
+	pub model: &'static str,
+	/// The name of the unrecognized field
+	pub key: String,
+}
+
+/// Lists all the unknown keys that have been seen since the start of the
+/// process or the last [`clear_unknown_keys`] call
+#[cfg(feature = "extra")]
+#[must_use]
+pub fn unknown_keys() -> BTreeSet<UnknownKey> {
+	UNKNOWN_KEYS.lock().unwrap_or_else(PoisonError::into_inner).clone()
+}
+
+/// Forgets all the unknown keys that have been seen so far
+#[cfg(feature = "extra")]
+pub fn clear_unknown_keys() {
+	UNKNOWN_KEYS.lock().unwrap_or_else(PoisonError::into_inner).clear();
+}
+
+/// Fields of the model `T` that weren't recognized when deserializing.
+///
+/// Dereferences to the underlying [`serde_json::Map`],
+/// which is always empty unless the `extra` feature is enabled.
+pub struct Extra<T> {
+	fields: Map<String, Value>,
+	model: PhantomData<fn() -> T>,
+}
+
+impl<T> Extra<T> {
+	/// Unwraps the unknown fields
+	#[must_use]
+	pub fn into_inner(self) -> Map<String, Value> { self.fields }
+}
+
+impl<T> Deref for Extra<T> {
+	type Target = Map<String, Value>;
+
+	fn deref(&self) -> &Self::Target { &self.fields }
+}
+
+impl<T> DerefMut for Extra<T> {
+	fn deref_mut(&mut self) -> &mut Self::Target { &mut self.fields }
+}
+
+impl<T> From<Map<String, Value>> for Extra<T> {
+	fn from(fields: Map<String, Value>) -> Self {
+		Self { fields, model: PhantomData }
+	}
+}
+
+impl<T> Default for Extra<T> {
+	fn default() -> Self { Map::new().into() }
+}
+
+impl<T> Clone for Extra<T> {
+	fn clone(&self) -> Self { self.fields.clone().into() }
+}
+
+impl<T> std::fmt::Debug for Extra<T> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		self.fields.fmt(f)
+	}
+}
+
+impl<T> PartialEq for Extra<T> {
+	fn eq(&self, other: &Self) -> bool { self.fields == other.fields }
+}
+
+impl<T> Eq for Extra<T> {}
+
+/// `serde_json` values can't be hashed, so only the keys are
+impl<T> std::hash::Hash for Extra<T> {
+	fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+		let mut keys: Vec<&String> = self.fields.keys().collect();
+		keys.sort_unstable();
+		std::hash::Hash::hash_slice(&keys, state);
+	}
+}
+
+impl<T> Serialize for Extra<T> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		self.fields.serialize(serializer)
+	}
+}
+
+/// Records the keys as `unknown_keys` when deserializing with the `extra`
+/// feature, and ignores the fields without it
+impl<'de, T> Deserialize<'de> for Extra<T> {
+	#[cfg(feature = "extra")]
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		let fields = Map::deserialize(deserializer)?;
+
+		if !fields.is_empty() {
+			let model = std::any::type_name::<T>();
+			let mut unknown_keys =
+				UNKNOWN_KEYS.lock().unwrap_or_else(PoisonError::into_inner);
+			for key in fields.keys() {
+				unknown_keys.insert(UnknownKey { model, key: key.clone() });
+			}
+		}
+
+		Ok(fields.into())
+	}
+
+	#[cfg(not(feature = "extra"))]
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		struct IgnoreFields;
+
+		impl<'de> serde::de::Visitor<'de> for IgnoreFields {
+			type Value = ();
+
+			fn expecting(
+				&self, formatter: &mut std::fmt::Formatter,
+			) -> std::fmt::Result {
+				formatter.write_str("a map of unknown fields")
+			}
+
+			fn visit_map<A>(self, mut map: A) -> Result<(), A::Error>
+			where
+				A: serde::de::MapAccess<'de>,
+			{
+				while map
+					.next_entry::<serde::de::IgnoredAny, serde::de::IgnoredAny>()?
+					.is_some()
+				{}
+				Ok(())
+			}
+		}
+
+		deserializer.deserialize_map(IgnoreFields)?;
+		Ok(Self::default())
+	}
+}
+
+#[cfg(all(test, feature = "extra"))]
+#[test]
+fn nested_flattening() {
+	let json = serde_json::json!({
+		"id": "usr_c1644b5b-3ca4-45b4-97c6-a2a0de70d469",
+		"displayName": "tupper",
+		"bio": "",
+		"bioLinks": [],
+		"currentAvatarImageUrl": "https://example.com/image.png",
+		"currentAvatarThumbnailImageUrl": "https://example.com/thumbnail.png",
+		"developerType": "internal",
+		"isFriend": false,
+		"last_platform": "standalonewindows",
+		"status": "active",
+		"statusDescription": "",
+		"tags": [],
+		"location": "offline",
+		"allowAvatarCopying": false,
+		"date_joined": "2017-01-01",
+		"friendRequestStatus": "outgoing",
+		"note": "",
+		"instanceId": "offline",
+		"worldId": "offline",
+		"brandNewField": true,
+	});
+
+	let user: crate::model::User = serde_json::from_value(json).unwrap();
+	assert_eq!(user.base.extra.keys().collect::<Vec<_>>(), ["brandNewField"]);
+	let json = serde_json::to_value(&user).unwrap();
+	assert_eq!(json["brandNewField"], serde_json::Value::Bool(true));
+	assert!(unknown_keys().contains(&UnknownKey {
+		model: std::any::type_name::<crate::model::AccountData>(),
+		key: "brandNewField".to_owned(),
+	}));
+}
+
+#[cfg(all(test, not(feature = "extra")))]
+#[test]
+fn ignored_without_feature() {
+	let status: crate::model::SecondFactorVerificationStatus =
+		serde_json::from_str(r#"{"verified":true,"brandNewField":{"a":[1]}}"#)
+			.unwrap();
+	assert!(status.verified);
+	assert!(status.extra.is_empty());
+	assert_eq!(serde_json::to_string(&status).unwrap(), r#"{"verified":true}"#);
+}
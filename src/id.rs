@@ -60,7 +60,6 @@ macro_rules! add_id {
 
 		impl AsRef<str> for $name {
 			/// Extracts a string slice containing the entire inner String.
-			#[must_use]
 			fn as_ref(&self) -> &str {
 				&self.0
 			}
@@ -78,7 +77,7 @@ macro_rules! add_id {
 
 			fn from_str(id: &str) -> Result<Self, Self::Err> {
 				if !$id_matches(&id) {
-					return Err(concat!("ID doesn't match expected format"))
+					return Err("ID doesn't match expected format")
 				}
 				Ok(Self(id.to_owned()))
 			}
@@ -162,7 +161,12 @@ add_id!(
 );
 add_id!(UnityPackage, |v: &str| v.starts_with("unp_") || v.len() == 10);
 add_id!(User, |v: &str| v.starts_with("usr_") || v.len() == 10);
+add_id!(UserNote, |v: &str| v.starts_with("unt_"));
 add_id!(GroupMember, |v: &str| v.starts_with("gmem_"));
+add_id!(GroupGallery, |v: &str| v.starts_with("ggal_"));
+add_id!(GroupGalleryImage, |v: &str| v.starts_with("ggim_"));
+add_id!(GroupRole, |v: &str| v.starts_with("grol_"));
+add_id!(GroupPost, |v: &str| v.starts_with("gpost_"));
 add_id!(World, |v: &str| v.starts_with("wrld_") || v.len() == 10);
 
 /// Offline or the id of the world or whatever type T is
@@ -298,15 +302,24 @@ pub enum Any {
 	UnityPackage(UnityPackage),
 	/// An user ID
 	User(User),
+	/// A user note ID
+	UserNote(UserNote),
 	/// A world ID
 	World(World),
 	/// A group member ID
 	GroupMember(GroupMember),
+	/// A group gallery ID
+	GroupGallery(GroupGallery),
+	/// A group gallery image ID
+	GroupGalleryImage(GroupGalleryImage),
+	/// A group role ID
+	GroupRole(GroupRole),
+	/// A group post ID
+	GroupPost(GroupPost),
 }
 
 impl AsRef<str> for Any {
 	/// Extracts a string slice containing the entire inner String.
-	#[must_use]
 	fn as_ref(&self) -> &str {
 		match self {
 			Self::Avatar(v) => v.as_ref(),
@@ -314,8 +327,13 @@ impl AsRef<str> for Any {
 			Self::Instance(v) => v.as_ref(),
 			Self::UnityPackage(v) => v.as_ref(),
 			Self::User(v) => v.as_ref(),
+			Self::UserNote(v) => v.as_ref(),
 			Self::World(v) => v.as_ref(),
 			Self::GroupMember(v) => v.as_ref(),
+			Self::GroupGallery(v) => v.as_ref(),
+			Self::GroupGalleryImage(v) => v.as_ref(),
+			Self::GroupRole(v) => v.as_ref(),
+			Self::GroupPost(v) => v.as_ref(),
 		}
 	}
 }
@@ -366,7 +384,7 @@ impl<'de> serde::de::Deserialize<'de> for WorldInstance {
 				&self, formatter: &mut std::fmt::Formatter,
 			) -> std::fmt::Result {
 				formatter
-					.write_str(concat!("a string, WorldInstance, that is of format `{vrc::id:World}:{vrc::id:Instance}`"))
+					.write_str("a string, WorldInstance, that is of format `{vrc::id:World}:{vrc::id:Instance}`")
 			}
 
 			fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
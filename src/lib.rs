@@ -47,6 +47,7 @@ time::serde::format_description!(date_format, Date, "[year]-[month]-[day]");
 
 #[cfg(feature = "api_client")]
 pub mod api_client;
+pub mod extra;
 
 /// Deals with the literal string `"none"` as `None`
 fn deserialize_optional_date<'de, D>(
@@ -1,14 +1,11 @@
 //! Avatars & worlds
 
 use serde::{Deserialize, Serialize};
-use strum::AsRefStr;
 use time::{OffsetDateTime, serde::rfc3339};
 use url::Url;
 
 /// If a world has been released publicly for example
-#[derive(
-	Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize, AsRefStr,
-)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum ReleaseStatus {
 	/// Publicly released
@@ -17,16 +14,177 @@ pub enum ReleaseStatus {
 	Private,
 	/// Not findable but also not private
 	Hidden,
+	/// Possible other values that may get added in the future
+	#[serde(untagged)]
+	Unknown(String),
+}
+
+impl AsRef<str> for ReleaseStatus {
+	fn as_ref(&self) -> &str {
+		match self {
+			Self::Public => "public",
+			Self::Private => "private",
+			Self::Hidden => "hidden",
+			Self::Unknown(v) => v,
+		}
+	}
+}
+
+/// A version of Unity, like `2022.3.22f1`
+#[derive(
+	Debug,
+	Clone,
+	PartialEq,
+	Eq,
+	PartialOrd,
+	Ord,
+	Hash,
+	serde_with::SerializeDisplay,
+	serde_with::DeserializeFromStr,
+)]
+pub struct UnityVersion {
+	/// The year of the release, or the major version since Unity 6
+	pub major: u16,
+	/// The minor version
+	pub minor: u16,
+	/// The patch version
+	pub patch: u16,
+	/// What kind of a release it is
+	pub release: UnityRelease,
+	/// The build number of the release
+	pub build: u16,
+	/// Letters and digits after the build number, like `c1` of Unity China
+	/// builds
+	pub suffix: String,
+}
+
+/// What kind of a release a [`UnityVersion`] is, from the least to the most
+/// stable
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum UnityRelease {
+	/// An experimental release, `x`
+	Experimental,
+	/// An alpha release, `a`
+	Alpha,
+	/// A beta release, `b`
+	Beta,
+	/// A final release, `f`
+	Final,
+	/// A patch release, `p`
+	Patch,
+}
+
+impl UnityRelease {
+	const fn letter(self) -> char {
+		match self {
+			Self::Experimental => 'x',
+			Self::Alpha => 'a',
+			Self::Beta => 'b',
+			Self::Final => 'f',
+			Self::Patch => 'p',
+		}
+	}
+}
+
+impl std::fmt::Display for UnityVersion {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		write!(
+			f,
+			"{}.{}.{}{}{}{}",
+			self.major,
+			self.minor,
+			self.patch,
+			self.release.letter(),
+			self.build,
+			self.suffix
+		)
+	}
+}
+
+/// Fallible: "2022.3.22f1".`parse::`<`UnityVersion`>()
+impl std::str::FromStr for UnityVersion {
+	type Err = &'static str;
+
+	fn from_str(version: &str) -> Result<Self, Self::Err> {
+		const FORMAT_ERROR: &str =
+			"Unity version should be of the format `2022.3.22f1`";
+
+		let mut parts = version.splitn(3, '.');
+		let (Some(major), Some(minor), Some(rest)) =
+			(parts.next(), parts.next(), parts.next())
+		else {
+			return Err(FORMAT_ERROR);
+		};
+		let release_start =
+			rest.find(|c: char| !c.is_ascii_digit()).ok_or(FORMAT_ERROR)?;
+		let (patch, rest) = rest.split_at(release_start);
+		let mut rest = rest.chars();
+		let release = match rest.next() {
+			Some('x') => UnityRelease::Experimental,
+			Some('a') => UnityRelease::Alpha,
+			Some('b') => UnityRelease::Beta,
+			Some('f') => UnityRelease::Final,
+			Some('p') => UnityRelease::Patch,
+			_ => {
+				return Err(
+					"Unity release type should be one of `x`, `a`, `b`, `f` or `p`",
+				);
+			}
+		};
+
+		let rest = rest.as_str();
+		let (build, suffix) = rest
+			.split_at(rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len()));
+
+		if !suffix.chars().all(|c| c.is_ascii_alphanumeric()) {
+			return Err("Unity version suffix should only have letters and digits");
+		}
+
+		let number = |part: &str| part.parse::<u16>().map_err(|_| FORMAT_ERROR);
+		Ok(Self {
+			major: number(major)?,
+			minor: number(minor)?,
+			patch: number(patch)?,
+			release,
+			build: number(build)?,
+			suffix: suffix.to_owned(),
+		})
+	}
 }
 
 /// Information about what platform the unity package supports
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UnityPackageSupports {
-	/// The platform that the package is for (`android` / `standalonewindows`)
-	pub platform: String,
-	/// The unity version
-	pub unity_version: String,
+	/// The platform that the package is for
+	pub platform: super::Platform,
+	/// The unity version that the package was built with
+	pub unity_version: UnityVersion,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+impl UnityPackageSupports {
+	/// Picks the package that a client on the platform and Unity version would
+	/// load, which is the newest one that isn't newer than the client
+	pub fn best_match<'a, T: AsRef<Self>>(
+		packages: impl IntoIterator<Item = &'a T>, platform: &super::Platform,
+		client_version: &UnityVersion,
+	) -> Option<&'a T> {
+		packages
+			.into_iter()
+			.filter(|package| {
+				let supports = package.as_ref();
+				&supports.platform == platform
+					&& &supports.unity_version <= client_version
+			})
+			.max_by(|a, b| a.as_ref().unity_version.cmp(&b.as_ref().unity_version))
+	}
+}
+
+impl AsRef<Self> for UnityPackageSupports {
+	fn as_ref(&self) -> &Self { self }
 }
 
 /// Information about an Unity package
@@ -40,6 +198,10 @@ pub struct UnityPackage {
 	pub supports: UnityPackageSupports,
 }
 
+impl AsRef<UnityPackageSupports> for UnityPackage {
+	fn as_ref(&self) -> &UnityPackageSupports { &self.supports }
+}
+
 /// Information about a VRC world
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -89,6 +251,9 @@ pub struct WorldData {
 		serialize_with = "rfc3339::option::serialize"
 	)]
 	pub publication_date: Option<OffsetDateTime>,
+	/// How many players the world is recommended for
+	#[serde(default)]
+	pub recommended_capacity: Option<u16>,
 	/// The release status of the world
 	pub release_status: ReleaseStatus,
 	/// The tags of the world
@@ -102,6 +267,157 @@ pub struct WorldData {
 	pub visits: u64,
 }
 
+impl WorldData {
+	/// Parses the tags of the world
+	pub fn world_tags(&self) -> impl Iterator<Item = WorldTag> + '_ {
+		self.tags.iter().map(|tag| WorldTag::from(tag.as_str()))
+	}
+
+	/// The content warnings of the world
+	pub fn content_warnings(&self) -> impl Iterator<Item = ContentWarning> + '_ {
+		self.world_tags().filter_map(|tag| match tag {
+			WorldTag::Content(warning) => Some(warning),
+			_ => None,
+		})
+	}
+
+	/// If the world has any content warnings
+	#[must_use]
+	pub fn has_content_warnings(&self) -> bool {
+		self.content_warnings().next().is_some()
+	}
+
+	/// The tags that the author has given to the world, without the prefix
+	pub fn author_tags(&self) -> impl Iterator<Item = &str> {
+		self.tags.iter().filter_map(|tag| tag.strip_prefix("author_tag_"))
+	}
+}
+
+/// A tag of a world, like `author_tag_chill` or `content_horror`
+#[derive(
+	Debug,
+	Clone,
+	PartialEq,
+	Eq,
+	Hash,
+	serde_with::SerializeDisplay,
+	serde_with::DeserializeFromStr,
+)]
+pub enum WorldTag {
+	/// A tag that the author has given, `author_tag_*` without the prefix
+	Author(String),
+	/// A content warning, `content_*`
+	Content(ContentWarning),
+	/// Has been approved to be public, `system_approved`
+	Approved,
+	/// Is in community labs, `system_labs`
+	Labs,
+	/// A tag set by the VRC team, `admin_*` without the prefix
+	Admin(String),
+	/// A feature that is toggled for the world, `feature_*` without the prefix
+	Feature(String),
+	/// Other tags set by VRC, `system_*` without the prefix
+	System(String),
+	/// Possible other values that may get added in the future
+	Unknown(String),
+}
+
+impl From<&str> for WorldTag {
+	fn from(tag: &str) -> Self {
+		if let Some(author) = tag.strip_prefix("author_tag_") {
+			return Self::Author(author.to_owned());
+		}
+		if let Some(content) = tag.strip_prefix("content_") {
+			return Self::Content(ContentWarning::from(content));
+		}
+		if let Some(admin) = tag.strip_prefix("admin_") {
+			return Self::Admin(admin.to_owned());
+		}
+		if let Some(feature) = tag.strip_prefix("feature_") {
+			return Self::Feature(feature.to_owned());
+		}
+
+		match tag.strip_prefix("system_") {
+			Some("approved") => Self::Approved,
+			Some("labs") => Self::Labs,
+			Some(system) => Self::System(system.to_owned()),
+			None => Self::Unknown(tag.to_owned()),
+		}
+	}
+}
+
+/// Infallible, unrecognized tags become [`WorldTag::Unknown`]
+impl std::str::FromStr for WorldTag {
+	type Err = std::convert::Infallible;
+
+	fn from_str(tag: &str) -> Result<Self, Self::Err> { Ok(Self::from(tag)) }
+}
+
+impl std::fmt::Display for WorldTag {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self {
+			Self::Author(author) => write!(f, "author_tag_{author}"),
+			Self::Content(warning) => write!(f, "content_{}", warning.as_ref()),
+			Self::Approved => f.write_str("system_approved"),
+			Self::Labs => f.write_str("system_labs"),
+			Self::Admin(admin) => write!(f, "admin_{admin}"),
+			Self::Feature(feature) => write!(f, "feature_{feature}"),
+			Self::System(system) => write!(f, "system_{system}"),
+			Self::Unknown(tag) => f.write_str(tag),
+		}
+	}
+}
+
+/// A content warning that a world has been tagged with
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ContentWarning {
+	/// Sexually suggestive content, `content_sex`
+	Sex,
+	/// Adult language and themes, `content_adult`
+	Adult,
+	/// Graphic violence, `content_violence`
+	Violence,
+	/// Excessive gore, `content_gore`
+	Gore,
+	/// Extreme horror, `content_horror`
+	Horror,
+	/// Possible other values that may get added in the future
+	Unknown(String),
+}
+
+impl ContentWarning {
+	/// All the known content warnings,
+	/// for example for excluding every warned world from a search
+	pub const KNOWN: [Self; 5] =
+		[Self::Sex, Self::Adult, Self::Violence, Self::Gore, Self::Horror];
+}
+
+impl From<&str> for ContentWarning {
+	fn from(warning: &str) -> Self {
+		match warning {
+			"sex" => Self::Sex,
+			"adult" => Self::Adult,
+			"violence" => Self::Violence,
+			"gore" => Self::Gore,
+			"horror" => Self::Horror,
+			_ => Self::Unknown(warning.to_owned()),
+		}
+	}
+}
+
+impl AsRef<str> for ContentWarning {
+	fn as_ref(&self) -> &str {
+		match self {
+			Self::Sex => "sex",
+			Self::Adult => "adult",
+			Self::Violence => "violence",
+			Self::Gore => "gore",
+			Self::Horror => "horror",
+			Self::Unknown(v) => v,
+		}
+	}
+}
+
 /// Limited information about a world
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -111,6 +427,37 @@ pub struct WorldListing {
 	pub base: WorldData,
 	/// Minimal listing of unity packages support
 	pub unity_packages: Vec<UnityPackageSupports>,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+impl WorldListing {
+	/// The package that a client on the platform and Unity version would load
+	#[must_use]
+	pub fn best_unity_package(
+		&self, platform: &super::Platform, client_version: &UnityVersion,
+	) -> Option<&UnityPackageSupports> {
+		UnityPackageSupports::best_match(
+			&self.unity_packages,
+			platform,
+			client_version,
+		)
+	}
+}
+
+/// A world in the favorites of the current user
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FavoritedWorld {
+	/// The ID of the favorite
+	pub favorite_id: String,
+	/// The name of the favorite group that the world is in
+	pub favorite_group: String,
+	/// The favorited world
+	// Flattened last, as it captures the unknown fields
+	#[serde(flatten)]
+	pub world: WorldListing,
 }
 
 /// Extended information about a world
@@ -120,6 +467,9 @@ pub struct World {
 	/// Base world data
 	#[serde(flatten)]
 	pub base: WorldData,
+	/// The description of the world
+	#[serde(default)]
+	pub description: String,
 	/// If the world is featured or not
 	pub featured: bool,
 	/// List of instances
@@ -132,13 +482,158 @@ pub struct World {
 	pub unity_packages: Vec<UnityPackage>,
 	/// The incrementing version of the world
 	pub version: u32,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+impl World {
+	/// The package that a client on the platform and Unity version would load
+	#[must_use]
+	pub fn best_unity_package(
+		&self, platform: &super::Platform, client_version: &UnityVersion,
+	) -> Option<&UnityPackage> {
+		UnityPackageSupports::best_match(
+			&self.unity_packages,
+			platform,
+			client_version,
+		)
+	}
+}
+
+/// If a world can be published to Community Labs
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorldPublishStatus {
+	/// If the world can be published right now
+	pub can_publish: bool,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
 }
 
 /// Information about a VRC avatar
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Avatar {
-	// TODO: Model
+	/// The ID of the avatar creator
+	pub author_id: crate::id::User,
+	/// The display name of the author
+	pub author_name: String,
+	/// When the avatar was initially uploaded
+	#[serde(rename = "created_at", with = "rfc3339")]
+	pub created_at: OffsetDateTime,
+	/// The description of the avatar
+	#[serde(default)]
+	pub description: String,
+	/// If the avatar is featured or not
+	#[serde(default)]
+	pub featured: bool,
 	/// The ID of the avatar
 	pub id: crate::id::Avatar,
+	/// An image for displaying the avatar
+	pub image_url: Url,
+	/// The name of the avatar
+	pub name: String,
+	/// The release status of the avatar
+	pub release_status: ReleaseStatus,
+	/// The tags of the avatar
+	#[serde(default)]
+	pub tags: Vec<String>,
+	/// A preview image of the avatar
+	pub thumbnail_image_url: Url,
+	/// Listing of unity packages support
+	#[serde(default)]
+	pub unity_packages: Vec<UnityPackage>,
+	/// When the avatar was last updated
+	#[serde(rename = "updated_at", with = "rfc3339")]
+	pub updated_at: OffsetDateTime,
+	/// The incrementing version of the avatar
+	pub version: u32,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+impl Avatar {
+	/// The package that a client on the platform and Unity version would load
+	#[must_use]
+	pub fn best_unity_package(
+		&self, platform: &super::Platform, client_version: &UnityVersion,
+	) -> Option<&UnityPackage> {
+		UnityPackageSupports::best_match(
+			&self.unity_packages,
+			platform,
+			client_version,
+		)
+	}
+}
+
+#[cfg(test)]
+#[test]
+fn world_tags() {
+	for tag in [
+		"author_tag_chill",
+		"content_gore",
+		"content_brand_new",
+		"system_approved",
+		"system_created_recently",
+		"admin_community_spotlight",
+		"feature_emoji_disabled",
+		"debug_allowed",
+	] {
+		assert_eq!(WorldTag::from(tag).to_string(), tag);
+	}
+
+	assert_eq!(
+		WorldTag::from("content_horror"),
+		WorldTag::Content(ContentWarning::Horror)
+	);
+	assert_eq!(WorldTag::from("system_labs"), WorldTag::Labs);
+}
+
+#[cfg(test)]
+#[test]
+fn unity_versions() {
+	use super::Platform;
+
+	let old: UnityVersion = "2019.4.40f1".parse().unwrap();
+	let new: UnityVersion = "2022.3.6f1".parse().unwrap();
+	assert!(old < new);
+	assert!(new < "2022.3.22f1".parse().unwrap());
+	assert!("2022.3.6b1".parse::<UnityVersion>().unwrap() < new);
+	assert_eq!(new.to_string(), "2022.3.6f1");
+	assert!("2022.3".parse::<UnityVersion>().is_err());
+	assert!("2022.3.6".parse::<UnityVersion>().is_err());
+
+	let china: UnityVersion = "2022.3.22f1c1".parse().unwrap();
+	assert_eq!(china.build, 1);
+	assert_eq!(china.suffix, "c1");
+	assert_eq!(china.to_string(), "2022.3.22f1c1");
+	assert!(new < china);
+	assert!("2022.3.22f1 ".parse::<UnityVersion>().is_err());
+	assert!("2022.3.22f1-c1".parse::<UnityVersion>().is_err());
+	assert!("2022.3.22f1c1\n".parse::<UnityVersion>().is_err());
+	let supports: UnityPackageSupports = serde_json::from_value(
+		serde_json::json!({ "platform": "android", "unityVersion": "2022.3.22f1c1" }),
+	)
+	.unwrap();
+	assert_eq!(supports.unity_version, china);
+
+	let packages: Vec<UnityPackageSupports> =
+		serde_json::from_value(serde_json::json!([
+			{ "platform": "standalonewindows", "unityVersion": "2019.4.40f1" },
+			{ "platform": "standalonewindows", "unityVersion": "2022.3.6f1" },
+			{ "platform": "android", "unityVersion": "2022.3.6f1" },
+			{ "platform": "standalonewindows", "unityVersion": "2023.1.1f1" },
+		]))
+		.unwrap();
+	let best = UnityPackageSupports::best_match(
+		&packages,
+		&Platform::StandaloneWindows,
+		&"2022.3.22f1".parse().unwrap(),
+	)
+	.unwrap();
+	assert_eq!(best.platform, Platform::StandaloneWindows);
+	assert_eq!(best.unity_version, new);
 }
@@ -3,17 +3,7 @@ use serde::{Deserialize, Serialize};
 use super::CurrentAccount;
 
 /// 2FA variants
-#[derive(
-	Clone,
-	Copy,
-	Debug,
-	Eq,
-	PartialEq,
-	Hash,
-	Serialize,
-	Deserialize,
-	strum::AsRefStr,
-)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum AdditionalAuthFactor {
 	/// Email code
@@ -22,6 +12,20 @@ pub enum AdditionalAuthFactor {
 	Totp,
 	/// Recovery code
 	Otp,
+	/// Possible other values that may get added in the future
+	#[serde(untagged)]
+	Unknown(String),
+}
+
+impl AsRef<str> for AdditionalAuthFactor {
+	fn as_ref(&self) -> &str {
+		match self {
+			Self::EmailOtp => "emailOtp",
+			Self::Totp => "totp",
+			Self::Otp => "otp",
+			Self::Unknown(v) => v,
+		}
+	}
 }
 
 /// Response from the API when logging in
@@ -31,6 +35,9 @@ pub struct LoginResponse {
 	/// If the login still requires 2FA
 	#[serde(rename = "requiresTwoFactorAuth")]
 	pub requires_additional_auth: Vec<AdditionalAuthFactor>,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
 }
 
 /// Possible response types from the current user endpoint
@@ -48,6 +55,9 @@ pub enum LoginResponseOrCurrentUser {
 pub struct AuthenticationError {
 	/// If the 2FA code was okay
 	pub verified: bool,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
 }
 
 /// Status of current authentication token
@@ -57,14 +67,17 @@ pub struct AuthStatus {
 	pub ok: bool,
 	/// The token that the authentication is using
 	pub token: String,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
 }
 
 impl std::fmt::Debug for AuthStatus {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		f.debug_struct("AuthStatus")
-			.field("ok", &self.ok)
-			.field("token", &"*****")
-			.finish()
+		let mut debug = f.debug_struct("AuthStatus");
+		debug.field("ok", &self.ok).field("token", &"*****");
+		debug.field("extra", &self.extra);
+		debug.finish()
 	}
 }
 
@@ -73,4 +86,7 @@ impl std::fmt::Debug for AuthStatus {
 pub struct SecondFactorVerificationStatus {
 	/// If the 2FA code was okay
 	pub verified: bool,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
 }
@@ -1,16 +1,105 @@
-use either::Either;
-use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use time::{OffsetDateTime, serde::rfc3339};
+use url::Url;
 
 use crate::id;
 
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+/// Who can see the group
+pub enum GroupPrivacy {
+	/// The group can be found by anyone
+	Default,
+	/// The group is hidden from non-members
+	Private,
+	/// Possible other values that may get added in the future
+	#[serde(untagged)]
+	Unknown(String),
+}
+
+impl AsRef<str> for GroupPrivacy {
+	fn as_ref(&self) -> &str {
+		match self {
+			Self::Default => "default",
+			Self::Private => "private",
+			Self::Unknown(v) => v,
+		}
+	}
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+/// How users can join the group
+pub enum GroupJoinState {
+	/// Nobody can join the group
+	Closed,
+	/// Users need to be invited to the group
+	Invite,
+	/// Users can request to join the group
+	Request,
+	/// Anyone can join the group
+	Open,
+	/// Possible other values that may get added in the future
+	#[serde(untagged)]
+	Unknown(String),
+}
+
+impl AsRef<str> for GroupJoinState {
+	fn as_ref(&self) -> &str {
+		match self {
+			Self::Closed => "closed",
+			Self::Invite => "invite",
+			Self::Request => "request",
+			Self::Open => "open",
+			Self::Unknown(v) => v,
+		}
+	}
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+/// The relation of an user to a group
+pub enum GroupMembershipStatus {
+	/// Not a member of the group
+	Inactive,
+	/// A member of the group
+	Member,
+	/// Has requested to join the group
+	Requested,
+	/// Has been invited to the group
+	Invited,
+	/// Has been banned from the group
+	Banned,
+	/// Has blocked the group
+	#[serde(rename = "userblocked")]
+	UserBlocked,
+	/// Possible other values that may get added in the future
+	#[serde(untagged)]
+	Unknown(String),
+}
+
+impl AsRef<str> for GroupMembershipStatus {
+	fn as_ref(&self) -> &str {
+		match self {
+			Self::Inactive => "inactive",
+			Self::Member => "member",
+			Self::Requested => "requested",
+			Self::Invited => "invited",
+			Self::Banned => "banned",
+			Self::UserBlocked => "userblocked",
+			Self::Unknown(v) => v,
+		}
+	}
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 /// Details about a VRC group
 pub struct Group {
 	/// The unique identifier for the group.
-	pub id: String,
+	pub id: id::Group,
 	/// The name of the group.
 	pub name: String,
 	/// The short code associated with the group.
@@ -20,43 +109,689 @@ pub struct Group {
 	/// The description of the group.
 	pub description: String,
 	/// The unique identifier for the group's icon.
-	pub icon_id: String,
+	#[serde(default)]
+	pub icon_id: Option<String>,
 	/// The URL of the group's icon.
-	pub icon_url: String,
+	#[serde(default)]
+	pub icon_url: Option<Url>,
 	/// The unique identifier for the group's banner.
-	pub banner_id: String,
+	#[serde(default)]
+	pub banner_id: Option<String>,
 	/// The URL of the group's banner.
-	pub banner_url: String,
+	#[serde(default)]
+	pub banner_url: Option<Url>,
 	/// The privacy setting of the group.
-	pub privacy: String,
+	pub privacy: GroupPrivacy,
 	/// The unique identifier of the owner of the group.
-	pub owner_id: String,
+	pub owner_id: id::User,
 	/// The rules associated with the group.
+	#[serde(default)]
 	pub rules: String,
 	/// The list of links associated with the group.
+	#[serde(default)]
 	pub links: Vec<String>,
 	/// The list of languages associated with the group.
+	#[serde(default)]
 	pub languages: Vec<String>,
 	/// The count of members in the group.
 	pub member_count: i64,
-	/// The times tamp when the member count was last synchronized.
-	pub member_count_synced_at: String,
+	/// The time stamp when the member count was last synchronized.
+	#[serde(with = "rfc3339")]
+	pub member_count_synced_at: OffsetDateTime,
 	/// Indicates whether the group is verified.
 	pub is_verified: bool,
 	/// The join state of the group.
-	pub join_state: String,
-	// pub tags: Vec<Value>,
-	// pub galleries: Vec<Value>,
+	pub join_state: GroupJoinState,
+	/// The tags of the group.
+	#[serde(default)]
+	pub tags: Vec<String>,
+	/// The galleries of the group.
+	#[serde(default)]
+	pub galleries: Vec<GroupGallery>,
 	/// The time stamp when the group was created.
-	pub created_at: String,
+	#[serde(with = "rfc3339")]
+	pub created_at: OffsetDateTime,
 	/// The count of online members in the group.
 	pub online_member_count: i64,
 	/// The membership status of the user.
-	pub membership_status: String,
+	pub membership_status: GroupMembershipStatus,
+	/// The membership of the current user, if they are a member.
+	#[serde(default)]
+	pub my_member: Option<GroupMyMember>,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+impl Group {
+	/// The code that users can share the group with, like `ABCD.1234`
+	#[must_use]
+	pub fn code(&self) -> GroupCode {
+		GroupCode {
+			short_code: self.short_code.clone(),
+			discriminator: self.discriminator.clone(),
+		}
+	}
+}
+
+#[derive(
+	Clone,
+	Debug,
+	Eq,
+	Hash,
+	PartialEq,
+	serde_with::SerializeDisplay,
+	serde_with::DeserializeFromStr,
+)]
+/// The short code and discriminator of a group, like `ABCD.1234`
+pub struct GroupCode {
+	/// The short code of the group, always in upper case
+	pub short_code: String,
+	/// The discriminator that separates groups with the same short code
+	pub discriminator: String,
+}
+
+impl GroupCode {
+	/// If the group has this code
+	#[must_use]
+	pub fn matches(&self, short_code: &str, discriminator: &str) -> bool {
+		self.short_code.eq_ignore_ascii_case(short_code)
+			&& self.discriminator == discriminator
+	}
+
+	/// Finds the group that has this code, like from group search results
+	pub fn find(
+		&self, groups: impl IntoIterator<Item = LimitedGroup>,
+	) -> Option<LimitedGroup> {
+		groups
+			.into_iter()
+			.find(|group| self.matches(&group.short_code, &group.discriminator))
+	}
+}
+
+impl std::fmt::Display for GroupCode {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		write!(f, "{}.{}", self.short_code, self.discriminator)
+	}
+}
+
+/// Fallible: "ABCD.1234".`parse::`<`GroupCode`>()
+impl std::str::FromStr for GroupCode {
+	type Err = &'static str;
+
+	fn from_str(code: &str) -> Result<Self, Self::Err> {
+		let (short_code, discriminator) = code.trim().split_once('.').ok_or(
+			"Group code should have a `.` between the short code and the discriminator",
+		)?;
+		if short_code.is_empty()
+			|| !short_code.chars().all(|c| c.is_ascii_alphanumeric())
+		{
+			return Err("Group short code should only have letters and numbers");
+		}
+		if discriminator.is_empty()
+			|| !discriminator.chars().all(|c| c.is_ascii_digit())
+		{
+			return Err("Group discriminator should only have numbers");
+		}
+		Ok(Self {
+			short_code: short_code.to_ascii_uppercase(),
+			discriminator: discriminator.to_owned(),
+		})
+	}
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+/// Details about a group, as returned by searches
+pub struct LimitedGroup {
+	/// The unique identifier for the group
+	pub id: id::Group,
+	/// The name of the group
+	pub name: String,
+	/// The short code associated with the group
+	pub short_code: String,
+	/// The discriminator for the group
+	pub discriminator: String,
+	/// The description of the group
+	#[serde(default)]
+	pub description: String,
+	/// The unique identifier for the group's icon
+	#[serde(default)]
+	pub icon_id: Option<String>,
+	/// The URL of the group's icon
+	#[serde(default)]
+	pub icon_url: Option<Url>,
+	/// The unique identifier for the group's banner
+	#[serde(default)]
+	pub banner_id: Option<String>,
+	/// The URL of the group's banner
+	#[serde(default)]
+	pub banner_url: Option<Url>,
+	/// The unique identifier of the owner of the group
+	pub owner_id: id::User,
+	/// The rules associated with the group
+	#[serde(default)]
+	pub rules: Option<String>,
+	/// The count of members in the group
+	pub member_count: i64,
+	/// The tags of the group
+	#[serde(default)]
+	pub tags: Vec<String>,
+	/// The galleries of the group
+	#[serde(default)]
+	pub galleries: Vec<GroupGallery>,
+	/// The time stamp when the group was created
+	#[serde(default)]
+	#[serde(with = "rfc3339::option")]
+	pub created_at: Option<OffsetDateTime>,
+	/// The membership status of the current user
+	#[serde(default)]
+	pub membership_status: Option<GroupMembershipStatus>,
+	/// If the group shows up in searches
+	#[serde(default)]
+	pub is_searchable: Option<bool>,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+impl LimitedGroup {
+	/// The code that users can share the group with, like `ABCD.1234`
+	#[must_use]
+	pub fn code(&self) -> GroupCode {
+		GroupCode {
+			short_code: self.short_code.clone(),
+			discriminator: self.discriminator.clone(),
+		}
+	}
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+/// A group that a user is a member of
+pub struct UserGroup {
+	/// The ID of the user's membership
+	pub id: id::GroupMember,
+	/// The ID of the group
+	pub group_id: id::Group,
+	/// The name of the group
+	pub name: String,
+	/// The short code associated with the group
+	pub short_code: String,
+	/// The discriminator for the group
+	pub discriminator: String,
+	/// The description of the group
+	#[serde(default)]
+	pub description: String,
+	/// The unique identifier for the group's icon
+	#[serde(default)]
+	pub icon_id: Option<String>,
+	/// The URL of the group's icon
+	#[serde(default)]
+	pub icon_url: Option<Url>,
+	/// The unique identifier for the group's banner
+	#[serde(default)]
+	pub banner_id: Option<String>,
+	/// The URL of the group's banner
+	#[serde(default)]
+	pub banner_url: Option<Url>,
+	/// The privacy setting of the group
+	#[serde(default)]
+	pub privacy: Option<GroupPrivacy>,
+	/// The unique identifier of the owner of the group
+	pub owner_id: id::User,
+	/// The count of members in the group
+	pub member_count: i64,
+	/// Who can see that the user is a member of the group
+	pub member_visibility: GroupMemberVisibility,
+	/// If the user is representing the group
+	pub is_representing: bool,
+	/// If the current user is also a member of the group
+	#[serde(default)]
+	pub mutual_group: bool,
+	#[serde(default)]
+	#[serde(with = "rfc3339::option")]
+	/// When the latest post was made in the group
+	pub last_post_created_at: Option<OffsetDateTime>,
+	#[serde(default)]
+	#[serde(with = "rfc3339::option")]
+	/// When the user last read the posts of the group
+	pub last_post_read_at: Option<OffsetDateTime>,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+impl UserGroup {
+	/// The code that users can share the group with, like `ABCD.1234`
+	#[must_use]
+	pub fn code(&self) -> GroupCode {
+		GroupCode {
+			short_code: self.short_code.clone(),
+			discriminator: self.discriminator.clone(),
+		}
+	}
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+/// The current user's membership in a group
+pub struct GroupMyMember {
+	/// The display name of who accepted the user into the group
+	#[serde(default)]
+	pub accepted_by_display_name: Option<String>,
+	/// Identifier of who accepted the user into the group
+	#[serde(default)]
+	pub accepted_by_id: Option<id::User>,
+	/// Notes made by the manager about the user
+	#[serde(default)]
+	pub manager_notes: Option<String>,
+	#[serde(default)]
+	#[serde(with = "rfc3339::option")]
+	/// The date and time when the user was banned from the group, if applicable
+	pub banned_at: Option<OffsetDateTime>,
+	/// If the user has 2FA enabled
+	#[serde(default, rename = "has2FA")]
+	pub has_2fa: bool,
+	/// The permissions that the user has in the group
+	#[serde(default)]
+	pub permissions: BTreeSet<GroupPermission>,
+	/// The details that every view of a group member has
+	#[serde(flatten)]
+	pub base: GroupMemberCore,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+/// A gallery of images in a group
+pub struct GroupGallery {
+	/// The unique identifier for the gallery
+	pub id: id::GroupGallery,
+	/// The name of the gallery
+	pub name: String,
+	/// The description of the gallery
+	#[serde(default)]
+	pub description: String,
+	/// If only members of the group can see the gallery
+	pub members_only: bool,
+	/// Roles that can view the gallery, everyone can if `None`
+	#[serde(default)]
+	pub role_ids_to_view: Option<Vec<id::GroupRole>>,
+	/// Roles that can submit images to the gallery, everyone can if `None`
+	#[serde(default)]
+	pub role_ids_to_submit: Option<Vec<id::GroupRole>>,
+	/// Roles whose submissions are approved automatically
+	#[serde(default)]
+	pub role_ids_to_auto_approve: Option<Vec<id::GroupRole>>,
+	/// Roles that can manage the gallery
+	#[serde(default)]
+	pub role_ids_to_manage: Option<Vec<id::GroupRole>>,
+	#[serde(default)]
+	#[serde(with = "rfc3339::option")]
+	/// When the gallery was created
+	pub created_at: Option<OffsetDateTime>,
+	#[serde(default)]
+	#[serde(with = "rfc3339::option")]
+	/// When the gallery was last updated
+	pub updated_at: Option<OffsetDateTime>,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+/// An image that has been submitted to a group gallery
+pub struct GroupGalleryImage {
+	/// The unique identifier for the image
+	pub id: id::GroupGalleryImage,
+	/// Identifier for the group
+	pub group_id: id::Group,
+	/// Identifier for the gallery that the image was submitted to
+	pub gallery_id: id::GroupGallery,
+	/// The ID of the image file
+	pub file_id: String,
+	/// The URL of the image
+	pub image_url: Url,
+	/// The user who submitted the image
+	pub submitted_by_user_id: id::User,
+	/// If the image has been approved to be shown in the gallery
+	pub approved: bool,
+	/// The user who approved the image
+	#[serde(default)]
+	pub approved_by_user_id: Option<id::User>,
+	#[serde(default)]
+	#[serde(with = "rfc3339::option")]
+	/// When the image was approved
+	pub approved_at: Option<OffsetDateTime>,
+	#[serde(with = "rfc3339")]
+	/// When the image was submitted
+	pub created_at: OffsetDateTime,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+#[derive(
+	Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+#[serde(rename_all = "kebab-case")]
+/// A permission that can be granted to the roles of a group
+pub enum GroupPermission {
+	/// Grants all the permissions
+	#[serde(rename = "*")]
+	All,
+	/// Manage group announcements
+	GroupAnnouncementManage,
+	/// View the audit log of the group
+	GroupAuditView,
+	/// Ban and un-ban users
+	GroupBansManage,
+	/// Manage the group's details
+	GroupDataManage,
+	/// Manage the default role of the group
+	GroupDefaultRoleManage,
+	/// Manage the galleries of the group
+	GroupGalleriesManage,
+	/// Create age gated group instances
+	GroupInstanceAgeGatedCreate,
+	/// Join group instances
+	GroupInstanceJoin,
+	/// Manage group instances
+	GroupInstanceManage,
+	/// Moderate group instances
+	GroupInstanceModerate,
+	/// Create group instances that are open to members
+	GroupInstanceOpenCreate,
+	/// Create group plus instances
+	GroupInstancePlusCreate,
+	/// Create portals to group plus instances
+	GroupInstancePlusPortal,
+	/// Create portals to group plus instances, even when closed
+	GroupInstancePlusPortalUnlocked,
+	/// Create public group instances
+	GroupInstancePublicCreate,
+	/// Have priority in the queues of group instances
+	GroupInstanceQueuePriority,
+	/// Create group instances that are restricted to roles
+	GroupInstanceRestrictedCreate,
+	/// Manage invites to the group
+	GroupInvitesManage,
+	/// Manage the members of the group
+	GroupMembersManage,
+	/// Remove members from the group
+	GroupMembersRemove,
+	/// View all the members of the group
+	GroupMembersViewall,
+	/// Assign roles to members
+	GroupRolesAssign,
+	/// Manage the roles of the group
+	GroupRolesManage,
+	/// Possible other values that may get added in the future
+	#[serde(untagged)]
+	Unknown(String),
+}
+
+impl AsRef<str> for GroupPermission {
+	fn as_ref(&self) -> &str {
+		match self {
+			Self::All => "*",
+			Self::GroupAnnouncementManage => "group-announcement-manage",
+			Self::GroupAuditView => "group-audit-view",
+			Self::GroupBansManage => "group-bans-manage",
+			Self::GroupDataManage => "group-data-manage",
+			Self::GroupDefaultRoleManage => "group-default-role-manage",
+			Self::GroupGalleriesManage => "group-galleries-manage",
+			Self::GroupInstanceAgeGatedCreate => "group-instance-age-gated-create",
+			Self::GroupInstanceJoin => "group-instance-join",
+			Self::GroupInstanceManage => "group-instance-manage",
+			Self::GroupInstanceModerate => "group-instance-moderate",
+			Self::GroupInstanceOpenCreate => "group-instance-open-create",
+			Self::GroupInstancePlusCreate => "group-instance-plus-create",
+			Self::GroupInstancePlusPortal => "group-instance-plus-portal",
+			Self::GroupInstancePlusPortalUnlocked => {
+				"group-instance-plus-portal-unlocked"
+			}
+			Self::GroupInstancePublicCreate => "group-instance-public-create",
+			Self::GroupInstanceQueuePriority => "group-instance-queue-priority",
+			Self::GroupInstanceRestrictedCreate => "group-instance-restricted-create",
+			Self::GroupInvitesManage => "group-invites-manage",
+			Self::GroupMembersManage => "group-members-manage",
+			Self::GroupMembersRemove => "group-members-remove",
+			Self::GroupMembersViewall => "group-members-viewall",
+			Self::GroupRolesAssign => "group-roles-assign",
+			Self::GroupRolesManage => "group-roles-manage",
+			Self::Unknown(v) => v,
+		}
+	}
+}
+
+impl GroupPermission {
+	/// If having this permission also grants the other permission,
+	/// taking wildcard permissions like `*` or `group-instance-*` into account
+	#[must_use]
+	pub fn grants(&self, permission: &Self) -> bool {
+		if self == permission {
+			return true;
+		}
+		self
+			.as_ref()
+			.strip_suffix('*')
+			.is_some_and(|prefix| permission.as_ref().starts_with(prefix))
+	}
+}
+
+/// The permissions that a group member has, for checking what they can do
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct GroupPermissionSet(pub BTreeSet<GroupPermission>);
+
+impl GroupPermissionSet {
+	/// The permissions of the current user in the group,
+	/// or `None` if the current user isn't a member of the group
+	#[must_use]
+	pub fn from_group(group: &Group) -> Option<Self> {
+		let my_member = group.my_member.as_ref()?;
+		let mut permissions = my_member.permissions.clone();
+		if my_member.base.user_id == group.owner_id {
+			permissions.insert(GroupPermission::All);
+		}
+		Some(Self(permissions))
+	}
+
+	/// The permissions that a member has due to their roles
+	#[must_use]
+	pub fn from_member(member: &GroupMemberCore, roles: &[GroupRole]) -> Self {
+		Self(
+			roles
+				.iter()
+				.filter(|role| {
+					member.role_ids.contains(&role.id)
+						|| member.m_role_ids.contains(&role.id)
+				})
+				.flat_map(|role| role.permissions.iter().cloned())
+				.collect(),
+		)
+	}
+
+	/// If any of the permissions grants the wanted permission
+	#[must_use]
+	pub fn has(&self, permission: &GroupPermission) -> bool {
+		self.0.iter().any(|granted| granted.grants(permission))
+	}
+
+	/// If users can be banned and un-banned
+	#[must_use]
+	pub fn can_ban(&self) -> bool { self.has(&GroupPermission::GroupBansManage) }
+
+	/// If members can be kicked
+	#[must_use]
+	pub fn can_kick(&self) -> bool {
+		self.has(&GroupPermission::GroupMembersRemove)
+	}
+
+	/// If roles can be created, updated and deleted
+	#[must_use]
+	pub fn can_manage_roles(&self) -> bool {
+		self.has(&GroupPermission::GroupRolesManage)
+	}
+
+	/// If roles can be given to and taken away from members
+	#[must_use]
+	pub fn can_assign_roles(&self) -> bool {
+		self.has(&GroupPermission::GroupRolesAssign)
+	}
+
+	/// If announcements can be posted
+	#[must_use]
+	pub fn can_post_announcements(&self) -> bool {
+		self.has(&GroupPermission::GroupAnnouncementManage)
+	}
+
+	/// If group instances can be moderated
+	#[must_use]
+	pub fn can_moderate_instances(&self) -> bool {
+		self.has(&GroupPermission::GroupInstanceModerate)
+	}
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+/// A role in a group
+pub struct GroupRole {
+	/// The unique identifier for the role
+	pub id: id::GroupRole,
+	/// Identifier for the group
+	pub group_id: id::Group,
+	/// The name of the role
+	pub name: String,
+	/// The description of the role
+	#[serde(default)]
+	pub description: String,
+	/// If members can give themselves the role
+	pub is_self_assignable: bool,
+	/// The permissions that the role grants
+	#[serde(default)]
+	pub permissions: BTreeSet<GroupPermission>,
+	/// If the role is for managing the group
+	pub is_management_role: bool,
+	/// If the role requires 2FA to be enabled
+	pub requires_two_factor: bool,
+	/// If the role requires a purchase
+	pub requires_purchase: bool,
+	/// The ordering of the role
+	pub order: u32,
+	#[serde(default)]
+	#[serde(with = "rfc3339::option")]
+	/// When the role was created
+	pub created_at: Option<OffsetDateTime>,
+	#[serde(default)]
+	#[serde(with = "rfc3339::option")]
+	/// When the role was last updated
+	pub updated_at: Option<OffsetDateTime>,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+#[derive(
+	Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize,
+)]
+#[serde(rename_all = "camelCase")]
+/// Who can see a group post
+pub enum GroupPostVisibility {
+	/// Only members of the group can see the post
+	#[default]
+	Group,
+	/// Everyone can see the post
+	Public,
+	/// A visibility that isn't known yet
+	#[serde(untagged)]
+	Unknown(String),
+}
+
+impl AsRef<str> for GroupPostVisibility {
+	fn as_ref(&self) -> &str {
+		match self {
+			Self::Group => "group",
+			Self::Public => "public",
+			Self::Unknown(visibility) => visibility,
+		}
+	}
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+/// A page of posts of a group
+pub struct GroupPosts {
+	/// The posts of the group
+	pub posts: Vec<GroupPost>,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+/// An announcement or other post in a group
+pub struct GroupPost {
+	/// The unique identifier for the post
+	pub id: id::GroupPost,
+	/// Identifier for the group
+	pub group_id: id::Group,
+	/// The user who wrote the post
+	pub author_id: id::User,
+	/// The user who last edited the post
+	#[serde(default)]
+	pub editor_id: Option<id::User>,
+	/// Who can see the post
+	pub visibility: GroupPostVisibility,
+	/// Roles that can see the post, all members can if empty
+	#[serde(default)]
+	pub role_ids: Vec<id::GroupRole>,
+	/// The title of the post
+	pub title: String,
+	/// The contents of the post
+	pub text: String,
+	/// The ID of the image file attached to the post
+	#[serde(default)]
+	pub image_id: Option<String>,
+	/// The URL of the image attached to the post
+	#[serde(default)]
+	pub image_url: Option<Url>,
+	#[serde(with = "rfc3339")]
+	/// When the post was created
+	pub created_at: OffsetDateTime,
+	#[serde(default)]
+	#[serde(with = "rfc3339::option")]
+	/// When the post was last updated
+	pub updated_at: Option<OffsetDateTime>,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
+/// A live instance that is owned by a group
+pub struct GroupInstance {
+	/// The ID of the instance
+	pub instance_id: id::Instance,
+	/// The ID of the world & instance
+	pub location: id::WorldInstance,
+	/// The world that the instance is running
+	pub world: super::WorldListing,
+	/// How many members of the group are in the instance
+	pub member_count: u32,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
 /// Represents a collection of group audit logs.
 pub struct GroupAuditLogs {
 	/// The list of group audit logs.
@@ -65,9 +800,12 @@ pub struct GroupAuditLogs {
 	pub total_count: u32,
 	/// Indicates whether there are more audit logs.
 	pub has_next: bool,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
 }
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 /// Represents a single group audit log entry.
 pub struct GroupAuditLog {
@@ -77,36 +815,389 @@ pub struct GroupAuditLog {
 	#[serde(rename = "created_at", with = "rfc3339")]
 	pub created_at: OffsetDateTime,
 	/// The unique identifier of the group associated with the audit log.
-	pub group_id: String,
+	pub group_id: id::Group,
 	/// The unique identifier of the actor who performed the action.
-	#[serde(with = "either::serde_untagged")]
-	pub actor_id: Either<id::User, String>,
+	pub actor_id: GroupAuditActor,
 	/// The display name of the actor.
 	pub actor_displayname: Option<String>,
 	/// The unique identifier of the target of the action.
-	#[serde(with = "either::serde_untagged_optional")]
-	pub target_id: Option<Either<id::User, id::Instance>>,
-	/// The type of event captured in the audit log.
-	pub event_type: String,
+	#[serde(default)]
+	pub target_id: Option<GroupAuditTarget>,
 	/// The description of the event captured in the audit log.
 	pub description: String,
-	/// Additional data associated with the audit log entry.
-	pub data: GroupAuditLogData,
+	/// The type of the event along with its data.
+	#[serde(flatten)]
+	pub event: GroupAuditEvent,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+/// Who performed a group audit log event
+pub enum GroupAuditActor {
+	/// VRC itself, for automated actions
+	#[serde(rename = "vrc_system")]
+	System,
+	/// A user, like a moderator of the group
+	#[serde(untagged)]
+	User(id::User),
+	/// Some other kind of an actor
+	#[serde(untagged)]
+	Other(String),
+}
+
+impl GroupAuditActor {
+	/// The ID of the user who performed the event, if it was a user
+	#[must_use]
+	pub const fn user_id(&self) -> Option<&id::User> {
+		match self {
+			Self::User(user_id) => Some(user_id),
+			Self::System | Self::Other(_) => None,
+		}
+	}
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(untagged)]
+/// The target of a group audit log event, depending on the event type
+// The prefixed IDs are tried first, as user IDs also accept legacy IDs
+pub enum GroupAuditTarget {
+	/// The group itself
+	Group(id::Group),
+	/// A role of the group
+	GroupRole(id::GroupRole),
+	/// A gallery of the group
+	GroupGallery(id::GroupGallery),
+	/// An instance of the group
+	WorldInstance(id::WorldInstance),
+	/// A user, like a member of the group
+	User(id::User),
+	/// Some other kind of a target
+	Other(String),
+}
+
+macro_rules! group_audit_events {
+	(
+		$(
+			$(#[$meta:meta])*
+			$event_type:literal => $variant:ident($data:ty),
+		)*
+	) => {
+		/// A typed group audit log event, keyed by the event type.
+		///
+		/// (De)serializes as the `eventType` and `data` fields of
+		/// [`GroupAuditLog`].
+		#[derive(Clone, Debug, Eq, PartialEq)]
+		pub enum GroupAuditEvent {
+			$(
+				$(#[$meta])*
+				#[doc = concat!("\n\nThe `", $event_type, "` event type")]
+				$variant($data),
+			)*
+			/// An event type that isn't known yet,
+			/// or which data didn't match the expected format
+			Unknown {
+				/// The type of the event
+				event_type: String,
+				/// The data of the event
+				data: serde_json::Value,
+			},
+		}
+
+		impl GroupAuditEvent {
+			/// The type of the event, like `group.member.join`
+			#[must_use]
+			pub fn event_type(&self) -> &str {
+				match self {
+					$(Self::$variant(_) => $event_type,)*
+					Self::Unknown { event_type, .. } => event_type,
+				}
+			}
+
+			fn data(&self) -> serde_json::Result<serde_json::Value> {
+				match self {
+					$(Self::$variant(data) => serde_json::to_value(data),)*
+					Self::Unknown { data, .. } => Ok(data.clone()),
+				}
+			}
+
+			fn from_raw(event_type: String, data: serde_json::Value) -> Self {
+				// Events without any data are typed the same as ones with empty data
+				let typed_data = if data.is_null() {
+					serde_json::Value::Object(serde_json::Map::new())
+				} else {
+					data.clone()
+				};
+				match event_type.as_str() {
+					$(
+						$event_type => match serde_json::from_value(typed_data.clone()) {
+							Ok(data) => Self::$variant(data),
+							Err(_) => Self::Unknown { event_type, data },
+						},
+					)*
+					_ => Self::Unknown { event_type, data },
+				}
+			}
+		}
+	};
+}
+
+group_audit_events! {
+	/// The group was created
+	"group.create" => Create(GroupAuditCreateData),
+	/// The details of the group were updated
+	"group.update" => Update(GroupAuditChanges),
+	/// An user joined the group
+	"group.member.join" => MemberJoin(GroupAuditMemberData),
+	/// A member left the group
+	"group.member.leave" => MemberLeave(GroupAuditMemberData),
+	/// A member was kicked from the group
+	"group.member.remove" => MemberRemove(GroupAuditMemberData),
+	/// The details of a member were updated
+	"group.member.user.update" => MemberUserUpdate(GroupAuditChanges),
+	/// A member was given a role
+	"group.member.role.assign" => MemberRoleAssign(GroupAuditRoleData),
+	/// A role was taken away from a member
+	"group.member.role.unassign" => MemberRoleUnassign(GroupAuditRoleData),
+	/// A role was created
+	"group.role.create" => RoleCreate(GroupAuditRoleData),
+	/// A role was updated
+	"group.role.update" => RoleUpdate(GroupAuditChanges),
+	/// A role was deleted
+	"group.role.delete" => RoleDelete(GroupAuditRoleData),
+	/// An user was banned from the group
+	"group.user.ban" => UserBan(GroupAuditBanData),
+	/// An user was un-banned from the group
+	"group.user.unban" => UserUnban(GroupAuditBanData),
+	/// A group instance was created
+	"group.instance.create" => InstanceCreate(GroupAuditInstanceData),
+	/// A group instance was closed
+	"group.instance.close" => InstanceClose(GroupAuditInstanceData),
+	/// An user was kicked from a group instance
+	"group.instance.kick" => InstanceKick(GroupAuditInstanceModerationData),
+	/// An user was warned in a group instance
+	"group.instance.warn" => InstanceWarn(GroupAuditInstanceModerationData),
+	/// An user was invited to the group
+	"group.invite.create" => InviteCreate(GroupAuditMemberData),
+	/// An user requested to join the group
+	"group.request.create" => RequestCreate(GroupAuditMemberData),
+	/// A request to join the group was rejected
+	"group.request.reject" => RequestReject(GroupAuditMemberData),
+	/// A post was created in the group
+	"group.post.create" => PostCreate(GroupAuditPostData),
+	/// A post was deleted from the group
+	"group.post.delete" => PostDelete(GroupAuditPostData),
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawGroupAuditEvent {
+	event_type: String,
+	#[serde(default)]
+	data: serde_json::Value,
+}
+
+impl<'de> Deserialize<'de> for GroupAuditEvent {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let raw = RawGroupAuditEvent::deserialize(deserializer)?;
+		Ok(Self::from_raw(raw.event_type, raw.data))
+	}
+}
+
+impl Serialize for GroupAuditEvent {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::{Error, SerializeStruct};
+
+		let data = self.data().map_err(S::Error::custom)?;
+		let mut state = serializer.serialize_struct("GroupAuditEvent", 2)?;
+		state.serialize_field("eventType", self.event_type())?;
+		state.serialize_field("data", &data)?;
+		state.end()
+	}
+}
+
+#[derive(
+	Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize,
+)]
+#[serde(rename_all = "camelCase")]
+/// Data of a group audit log event about the group being created
+pub struct GroupAuditCreateData {
+	/// The name of the group
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
+	/// The short code of the group
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub short_code: Option<String>,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+#[derive(
+	Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize,
+)]
+#[serde(rename_all = "camelCase")]
+/// Data of a group audit log event about a user joining, leaving or being
+/// removed from the group, or being invited or requesting to join it
+pub struct GroupAuditMemberData {
+	/// The ID of the user
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub user_id: Option<id::User>,
+	/// The display name of the user
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub display_name: Option<String>,
+	/// The roles that the user was given
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub role_ids: Option<Vec<id::GroupRole>>,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+#[derive(
+	Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize,
+)]
+#[serde(rename_all = "camelCase")]
+/// Data of a group audit log event about a user being banned or unbanned
+pub struct GroupAuditBanData {
+	/// The ID of the user
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub user_id: Option<id::User>,
+	/// The reason that was given
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub reason: Option<String>,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+#[derive(
+	Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize,
+)]
 #[serde(rename_all = "camelCase")]
-/// Represents additional data associated with a group audit log entry.
-pub struct GroupAuditLogData {
-	/// The description change associated with the audit log entry.
-	#[serde(default, with = "either::serde_untagged_optional")]
-	pub description: Option<Either<GroupAuditLogDataChange<String>, String>>,
-	/// The join state change associated with the audit log entry.
-	#[serde(default, with = "either::serde_untagged_optional")]
-	pub join_state: Option<Either<GroupAuditLogDataChange<String>, String>>,
-	/// The order change associated with the audit log entry.
-	#[serde(default, with = "either::serde_untagged_optional")]
-	pub order: Option<Either<GroupAuditLogDataChange<u32>, u32>>,
+/// Data of a group audit log event about a role
+pub struct GroupAuditRoleData {
+	/// The ID of the role
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub role_id: Option<id::GroupRole>,
+	/// The name of the role
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub role_name: Option<String>,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+#[derive(
+	Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize,
+)]
+#[serde(rename_all = "camelCase")]
+/// Data of a group audit log event about an instance
+pub struct GroupAuditInstanceData {
+	/// The instance
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub location: Option<id::WorldInstance>,
+	/// Who can access the instance
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub group_access_type: Option<super::GroupAccessType>,
+	/// The roles that are allowed to join the instance
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub role_ids: Option<Vec<id::GroupRole>>,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+#[derive(
+	Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize,
+)]
+#[serde(rename_all = "camelCase")]
+/// Data of a group audit log event about a user being moderated in a group
+/// instance
+pub struct GroupAuditInstanceModerationData {
+	/// The instance that the user was in
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub location: Option<id::WorldInstance>,
+	/// The ID of the user
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub user_id: Option<id::User>,
+	/// The reason that was given
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub reason: Option<String>,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+#[derive(
+	Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize,
+)]
+#[serde(rename_all = "camelCase")]
+/// Data of a group audit log event about a post
+pub struct GroupAuditPostData {
+	/// The ID of the post
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub post_id: Option<id::GroupPost>,
+	/// The title of the post
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub title: Option<String>,
+	/// The text of the post
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub text: Option<String>,
+	/// Who can see the post
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub visibility: Option<GroupPostVisibility>,
+	/// The roles that can see the post
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub role_ids: Option<Vec<id::GroupRole>>,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(transparent)]
+/// The changes of a group audit log event that updates something,
+/// keyed by the name of the changed field
+pub struct GroupAuditChanges(pub BTreeMap<String, GroupAuditChange>);
+
+impl GroupAuditChanges {
+	/// The new value of the field, if it changed and has the expected type
+	#[must_use]
+	pub fn new_value<T: DeserializeOwned>(&self, field: &str) -> Option<T> {
+		let value = match self.0.get(field)? {
+			GroupAuditChange::Changed(change) => &change.new,
+			GroupAuditChange::Set(value) => value,
+		};
+		T::deserialize(value).ok()
+	}
+
+	/// The old value of the field, if it changed, the old value is known and
+	/// it has the expected type
+	#[must_use]
+	pub fn old_value<T: DeserializeOwned>(&self, field: &str) -> Option<T> {
+		match self.0.get(field)? {
+			GroupAuditChange::Changed(change) => T::deserialize(&change.old).ok(),
+			GroupAuditChange::Set(_) => None,
+		}
+	}
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(untagged)]
+/// A change of a field in a group audit log event
+pub enum GroupAuditChange {
+	/// The field changed from the old value to the new one
+	Changed(GroupAuditLogDataChange<serde_json::Value>),
+	/// Only the new value of the field is known
+	Set(serde_json::Value),
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
@@ -117,62 +1208,44 @@ pub struct GroupAuditLogDataChange<T> {
 	pub old: T,
 	/// The new field after the change.
 	pub new: T,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
 }
 
-// TODO: Merge `GroupBan` amd `GroupMember` common fields
-
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(
+	Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize,
+)]
 #[serde(rename_all = "camelCase")]
-/// Details about a group ban/un-ban.
-pub struct GroupBan {
-	/// Identifier of the group member
-	pub id: id::GroupMember,
-	/// Identifier of the group.
-	pub group_id: id::Group,
-	/// Identifier of the user who was banned.
-	pub user_id: id::User,
-	/// Flag indicating if the user was representing the group at the time of
-	/// ban.
-	pub is_representing: bool,
-	/// List of role identifiers the user had in the group.
-	pub role_ids: Vec<Value>,
-	// TODO: Rename
-	/// List of managed role identifiers the user had in the group.
-	pub m_role_ids: Vec<Value>,
-	#[serde(default)]
-	#[serde(with = "rfc3339::option")]
-	/// Time of when the user joined.
-	pub joined_at: Option<OffsetDateTime>,
-	/// Status of the user's membership in the group at the time of ban.
-	pub membership_status: String,
-	/// Visibility status of the user in the group.
-	pub visibility: String,
-	/// Flag indicating if the user was subscribed to group announcements.
-	pub is_subscribed_to_announcements: bool,
-	#[serde(default)]
-	#[serde(with = "rfc3339::option")]
-	/// Time of the last post read by the user in the group.
-	pub last_post_read_at: Option<OffsetDateTime>,
-	#[serde(default)]
-	#[serde(with = "rfc3339::option")]
-	/// Time when the user joined the group.
-	pub created_at: Option<OffsetDateTime>,
-	#[serde(default)]
-	#[serde(with = "rfc3339::option")]
-	/// Time when the user was banned from the group.
-	pub banned_at: Option<OffsetDateTime>,
-	#[serde(default)]
-	/// Notes added by the group manager regarding the ban.
-	pub manager_notes: String,
-	/// Flag indicating if the user joined the group from a purchase.
-	pub has_joined_from_purchase: bool,
+/// Who can see that the user is a member of the group
+pub enum GroupMemberVisibility {
+	/// Everyone can see the membership
+	#[default]
+	Visible,
+	/// Only friends can see the membership
+	Friends,
+	/// No one else can see the membership
+	Hidden,
+	/// Possible other values that may get added in the future
+	#[serde(untagged)]
+	Unknown(String),
+}
+
+impl AsRef<str> for GroupMemberVisibility {
+	fn as_ref(&self) -> &str {
+		match self {
+			Self::Visible => "visible",
+			Self::Friends => "friends",
+			Self::Hidden => "hidden",
+			Self::Unknown(visibility) => visibility,
+		}
+	}
 }
 
-// TODO: Split limited group member away from what admin endpoint gives
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
-/// Details about a group member.
-pub struct GroupMember {
+/// The details that every view of a group member has
+pub struct GroupMemberCore {
 	/// Unique identifier for the group member
 	pub id: id::GroupMember,
 	/// Identifier for the group
@@ -182,18 +1255,19 @@ pub struct GroupMember {
 	/// This field indicates whether the user is representing the group or not
 	pub is_representing: bool,
 	/// List of role identifiers associated with the user in the group
-	pub role_ids: Vec<String>,
+	#[serde(default)]
+	pub role_ids: Vec<id::GroupRole>,
 	/// List of manager role identifiers associated with the user in the group
-	pub m_role_ids: Vec<String>,
+	#[serde(default)]
+	pub m_role_ids: Vec<id::GroupRole>,
 	#[serde(default)]
 	#[serde(with = "rfc3339::option")]
 	/// The date and time when the user joined the group
 	pub joined_at: Option<OffsetDateTime>,
 	/// The status of the user's membership in the group
-	pub membership_status: String,
-	// TODO: Enum
-	/// The visibility status of the user in the group
-	pub visibility: String,
+	pub membership_status: GroupMembershipStatus,
+	/// Who can see that the user is a member of the group
+	pub visibility: GroupMemberVisibility,
 	/// This field indicates whether the user is subscribed to group
 	/// announcements or not
 	pub is_subscribed_to_announcements: bool,
@@ -205,13 +1279,306 @@ pub struct GroupMember {
 	#[serde(with = "rfc3339::option")]
 	/// The date and time when the group member was created
 	pub created_at: Option<OffsetDateTime>,
+	/// This field indicates whether the user has joined the group from a
+	/// purchase or not
+	#[serde(default)]
+	pub has_joined_from_purchase: Option<bool>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+/// A group member, as seen by anyone who can see the membership
+pub struct LimitedGroupMember {
+	/// The details that every view of a group member has
+	#[serde(flatten)]
+	pub base: GroupMemberCore,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+/// A group member, as seen by the managers of the group
+pub struct FullGroupMember {
+	/// Notes made by the manager about the user
+	#[serde(default)]
+	pub manager_notes: Option<String>,
 	#[serde(default)]
 	#[serde(with = "rfc3339::option")]
 	/// The date and time when the user was banned from the group, if applicable
 	pub banned_at: Option<OffsetDateTime>,
-	/// Notes made by the manager about the user
-	pub manager_notes: Option<String>,
-	/// This field indicates whether the user has joined the group from a
-	/// purchase or not
-	pub has_joined_from_purchase: Option<bool>,
+	/// A summary of the user
+	#[serde(default)]
+	pub user: Option<GroupMemberUser>,
+	/// The details that every view of a group member has
+	#[serde(flatten)]
+	pub base: GroupMemberCore,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+#[serde_with::serde_as]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+/// A summary of the user of a group member
+pub struct GroupMemberUser {
+	/// The ID of the user
+	pub id: id::User,
+	/// The display name of the user
+	pub display_name: String,
+	/// The avatar's smaller image, can be an empty string
+	#[serde(default)]
+	#[serde_as(as = "serde_with::NoneAsEmptyString")]
+	pub current_avatar_thumbnail_image_url: Option<Url>,
+	/// The current avatar tags
+	#[serde(default)]
+	pub current_avatar_tags: Vec<String>,
+	/// URL to the user's icon, can be an empty string
+	#[serde(default)]
+	#[serde_as(as = "serde_with::NoneAsEmptyString")]
+	pub icon_url: Option<Url>,
+	/// URL to the user's profile picture, can be an empty string
+	#[serde(default)]
+	#[serde_as(as = "serde_with::NoneAsEmptyString")]
+	pub profile_pic_override: Option<Url>,
+	/// URL to the user's thumbnail, can be an empty string
+	#[serde(default)]
+	#[serde_as(as = "serde_with::NoneAsEmptyString")]
+	pub thumbnail_url: Option<Url>,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+#[cfg(test)]
+#[test]
+fn group_with_my_member() {
+	let group: Group = serde_json::from_value(serde_json::json!({
+		"id": "grp_93451756-8327-4ecc-b978-3e60aa9f64a9",
+		"name": "Onlivfe",
+		"shortCode": "ONLIV",
+		"discriminator": "1234",
+		"description": "",
+		"iconId": null,
+		"iconUrl": null,
+		"bannerId": "file_c1644b5b-3ca4-45b4-97c6-a2a0de70d469",
+		"bannerUrl": "https://example.com/banner.png",
+		"privacy": "default",
+		"ownerId": "usr_c1644b5b-3ca4-45b4-97c6-a2a0de70d469",
+		"rules": "",
+		"links": [],
+		"languages": ["eng"],
+		"memberCount": 2,
+		"memberCountSyncedAt": "2024-01-01T12:00:00.000Z",
+		"isVerified": false,
+		"joinState": "request",
+		"tags": ["admin_hidden_tag"],
+		"galleries": [{
+			"id": "ggal_c1644b5b-3ca4-45b4-97c6-a2a0de70d469",
+			"name": "Photos",
+			"description": "",
+			"membersOnly": true,
+			"roleIdsToView": null,
+			"createdAt": "2024-01-01T12:00:00.000Z",
+		}],
+		"createdAt": "2023-01-01T12:00:00.000Z",
+		"onlineMemberCount": 1,
+		"membershipStatus": "member",
+		"myMember": {
+			"id": "gmem_c1644b5b-3ca4-45b4-97c6-a2a0de70d469",
+			"groupId": "grp_93451756-8327-4ecc-b978-3e60aa9f64a9",
+			"userId": "usr_c1644b5b-3ca4-45b4-97c6-a2a0de70d469",
+			"roleIds": [],
+			"membershipStatus": "member",
+			"isSubscribedToAnnouncements": true,
+			"visibility": "visible",
+			"isRepresenting": false,
+			"joinedAt": "2023-01-01T12:00:00.000Z",
+			"has2FA": true,
+			"hasJoinedFromPurchase": false,
+			"permissions": ["*"],
+		},
+	}))
+	.unwrap();
+
+	assert_eq!(group.join_state, GroupJoinState::Request);
+	assert_eq!(group.galleries.len(), 1);
+	let my_member = group.my_member.expect("my member to be set");
+	assert_eq!(my_member.base.membership_status, GroupMembershipStatus::Member);
+	assert!(my_member.has_2fa);
+	assert_eq!(my_member.base.has_joined_from_purchase, Some(false));
+	assert!(my_member.permissions.contains(&GroupPermission::All));
+}
+
+#[cfg(test)]
+#[test]
+fn wildcard_permissions() {
+	let instance_wildcard: GroupPermission =
+		serde_json::from_str("\"group-instance-*\"").unwrap();
+	assert!(instance_wildcard.grants(&GroupPermission::GroupInstanceModerate));
+	assert!(!instance_wildcard.grants(&GroupPermission::GroupBansManage));
+	assert!(GroupPermission::All.grants(&GroupPermission::GroupBansManage));
+
+	let permissions = GroupPermissionSet(BTreeSet::from([instance_wildcard]));
+	assert!(permissions.can_moderate_instances());
+	assert!(!permissions.can_ban());
+	assert!(!permissions.can_kick());
+}
+
+#[cfg(test)]
+#[test]
+fn audit_log_events() {
+	let logs: GroupAuditLogs = serde_json::from_value(serde_json::json!({
+		"results": [{
+			"id": "gaud_c1644b5b-3ca4-45b4-97c6-a2a0de70d469",
+			"created_at": "2024-01-01T12:00:00.000Z",
+			"groupId": "grp_93451756-8327-4ecc-b978-3e60aa9f64a9",
+			"actorId": "usr_c1644b5b-3ca4-45b4-97c6-a2a0de70d469",
+			"actorDisplayName": "tupper",
+			"targetId": "grp_93451756-8327-4ecc-b978-3e60aa9f64a9",
+			"eventType": "group.update",
+			"description": "Group join state changed",
+			"data": {
+				"joinState": { "old": "closed", "new": "request" },
+				"name": "New name",
+			},
+		}, {
+			"id": "gaud_93451756-8327-4ecc-b978-3e60aa9f64a9",
+			"created_at": "2024-01-01T12:00:00.000Z",
+			"groupId": "grp_93451756-8327-4ecc-b978-3e60aa9f64a9",
+			"actorId": "vrc_system",
+			"targetId": null,
+			"eventType": "group.brand.new",
+			"description": "Something new happened",
+			"data": { "something": 1 },
+		}],
+		"totalCount": 2,
+		"hasNext": false,
+	}))
+	.unwrap();
+
+	let GroupAuditEvent::Update(changes) = &logs.results[0].event else {
+		panic!("expected a group update event");
+	};
+	assert_eq!(changes.new_value("joinState"), Some(GroupJoinState::Request));
+	assert_eq!(changes.old_value("joinState"), Some(GroupJoinState::Closed));
+	assert_eq!(changes.new_value::<String>("name"), Some("New name".to_owned()));
+	assert_eq!(changes.old_value::<String>("name"), None);
+	let json = serde_json::to_value(&logs.results[0]).unwrap();
+	assert_eq!(json["data"]["name"], "New name");
+	assert_eq!(json["data"]["joinState"]["old"], "closed");
+	assert_eq!(
+		logs.results[0].target_id,
+		Some(GroupAuditTarget::Group(
+			"grp_93451756-8327-4ecc-b978-3e60aa9f64a9".into()
+		))
+	);
+
+	for data in [None, Some(serde_json::Value::Null)] {
+		let mut log = serde_json::json!({
+			"id": "gaud_0e4d1b1c-96ef-4f62-b3b4-1a6fd7c6bfc6",
+			"created_at": "2024-01-01T12:00:00.000Z",
+			"groupId": "grp_93451756-8327-4ecc-b978-3e60aa9f64a9",
+			"actorId": "usr_c1644b5b-3ca4-45b4-97c6-a2a0de70d469",
+			"targetId": "usr_0e4d1b1c-96ef-4f62-b3b4-1a6fd7c6bfc6",
+			"eventType": "group.user.ban",
+			"description": "User was banned",
+		});
+		if let Some(data) = data {
+			log["data"] = data;
+		}
+		let log: GroupAuditLog = serde_json::from_value(log).unwrap();
+		assert!(matches!(log.event, GroupAuditEvent::UserBan(_)));
+	}
+
+	let log: GroupAuditLog = serde_json::from_value(serde_json::json!({
+		"id": "gaud_0e4d1b1c-96ef-4f62-b3b4-1a6fd7c6bfc6",
+		"created_at": "2024-01-01T12:00:00.000Z",
+		"groupId": "grp_93451756-8327-4ecc-b978-3e60aa9f64a9",
+		"actorId": "usr_c1644b5b-3ca4-45b4-97c6-a2a0de70d469",
+		"eventType": "group.instance.kick",
+		"description": "User was kicked",
+		"data": { "reason": "spamming" },
+	}))
+	.unwrap();
+	let GroupAuditEvent::InstanceKick(data) = &log.event else {
+		panic!("expected an instance kick event");
+	};
+	assert_eq!(data.reason.as_deref(), Some("spamming"));
+	assert_eq!(serde_json::to_value(&log).unwrap()["data"]["reason"], "spamming");
+
+	assert_eq!(
+		logs.results[0].actor_id.user_id(),
+		Some(&"usr_c1644b5b-3ca4-45b4-97c6-a2a0de70d469".into())
+	);
+	assert_eq!(logs.results[1].actor_id, GroupAuditActor::System);
+
+	for (target, expected) in [
+		("grp_abcdef", GroupAuditTarget::Group("grp_abcdef".into())),
+		("grol_abcde", GroupAuditTarget::GroupRole("grol_abcde".into())),
+		("8JoV9XEdpo", GroupAuditTarget::User("8JoV9XEdpo".into())),
+		("something", GroupAuditTarget::Other("something".to_owned())),
+	] {
+		assert_eq!(
+			serde_json::from_value::<GroupAuditTarget>(target.into()).unwrap(),
+			expected
+		);
+	}
+
+	let unknown = &logs.results[1];
+	assert_eq!(unknown.event.event_type(), "group.brand.new");
+	let json = serde_json::to_value(unknown).unwrap();
+	assert_eq!(json["actorId"], "vrc_system");
+	assert_eq!(json["eventType"], "group.brand.new");
+	assert_eq!(json["data"]["something"], 1);
+}
+
+#[cfg(test)]
+#[test]
+fn full_group_member() {
+	let member: FullGroupMember = serde_json::from_value(serde_json::json!({
+		"id": "gmem_c1644b5b-3ca4-45b4-97c6-a2a0de70d469",
+		"groupId": "grp_93451756-8327-4ecc-b978-3e60aa9f64a9",
+		"userId": "usr_c1644b5b-3ca4-45b4-97c6-a2a0de70d469",
+		"isRepresenting": false,
+		"user": {
+			"id": "usr_c1644b5b-3ca4-45b4-97c6-a2a0de70d469",
+			"displayName": "tupper",
+			"thumbnailUrl": "",
+			"iconUrl": "https://example.com/icon.png",
+		},
+		"roleIds": ["grol_c1644b5b-3ca4-45b4-97c6-a2a0de70d469"],
+		"mRoleIds": [],
+		"joinedAt": "2023-01-01T12:00:00.000Z",
+		"membershipStatus": "banned",
+		"visibility": "friends",
+		"isSubscribedToAnnouncements": false,
+		"createdAt": "2023-01-01T12:00:00.000Z",
+		"bannedAt": "2024-01-01T12:00:00.000Z",
+		"managerNotes": null,
+		"hasJoinedFromPurchase": false,
+	}))
+	.unwrap();
+
+	assert_eq!(member.base.membership_status, GroupMembershipStatus::Banned);
+	assert_eq!(member.base.visibility, GroupMemberVisibility::Friends);
+	assert!(member.banned_at.is_some());
+	let user = member.user.expect("user summary to be set");
+	assert_eq!(user.thumbnail_url, None);
+	assert!(user.icon_url.is_some());
+	assert!(member.extra.is_empty());
+}
+
+#[cfg(test)]
+#[test]
+fn group_codes() {
+	let code: GroupCode = "abcd.1234".parse().unwrap();
+	assert_eq!(code.to_string(), "ABCD.1234");
+	assert!(code.matches("ABCD", "1234"));
+	assert!(!code.matches("ABCD", "4321"));
+	assert!("ABCD".parse::<GroupCode>().is_err());
+	assert!("ABCD.12a4".parse::<GroupCode>().is_err());
+	assert!(".1234".parse::<GroupCode>().is_err());
 }
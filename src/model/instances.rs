@@ -15,6 +15,48 @@ pub enum InstancePrivacy {
 	Group,
 	/// Invite or Invite Plus
 	Private,
+	/// Possible other values that may get added in the future
+	#[serde(untagged)]
+	Unknown(String),
+}
+
+impl AsRef<str> for InstancePrivacy {
+	fn as_ref(&self) -> &str {
+		match self {
+			Self::Public => "public",
+			Self::FriendsOfFriends => "hidden",
+			Self::Friends => "friends",
+			Self::Group => "group",
+			Self::Private => "private",
+			Self::Unknown(v) => v,
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+/// Who can join a group instance
+pub enum GroupAccessType {
+	/// Only members of the group, optionally limited to some roles
+	Members,
+	/// Members of the group and their friends, also known as `Group+`
+	Plus,
+	/// Anyone, also known as `Group Public`
+	Public,
+	/// Possible other values that may get added in the future
+	#[serde(untagged)]
+	Unknown(String),
+}
+
+impl AsRef<str> for GroupAccessType {
+	fn as_ref(&self) -> &str {
+		match self {
+			Self::Members => "members",
+			Self::Plus => "plus",
+			Self::Public => "public",
+			Self::Unknown(v) => v,
+		}
+	}
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
@@ -35,9 +77,22 @@ pub enum InstanceRegion {
 	#[serde(rename = "jp")]
 	/// Hosted in Tokyo
 	Japan,
-	#[serde(other)]
 	/// Possible other values that may get added in the future
-	Unknown,
+	#[serde(untagged)]
+	Unknown(String),
+}
+
+impl AsRef<str> for InstanceRegion {
+	fn as_ref(&self) -> &str {
+		match self {
+			Self::Usa => "us",
+			Self::UsaWest => "usw",
+			Self::UsaEast => "use",
+			Self::Europe => "eu",
+			Self::Japan => "jp",
+			Self::Unknown(v) => v,
+		}
+	}
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
@@ -53,14 +108,25 @@ pub enum InstanceCreatorId {
 	Private(crate::id::User),
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[derive(
+	Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize, Serialize,
+)]
+#[serde(transparent)]
 /// How many users are on each platform
-pub struct InstancePlatformUserCounter {
-	/// How many quest users there are
-	pub android: u16,
-	/// How many desktop users there are
-	#[serde(rename = "standalonewindows")]
-	pub windows: u16,
+pub struct InstancePlatformUserCounter(
+	pub std::collections::BTreeMap<super::Platform, u32>,
+);
+
+impl InstancePlatformUserCounter {
+	/// How many users there are on the platform
+	#[must_use]
+	pub fn count(&self, platform: &super::Platform) -> u32 {
+		self.0.get(platform).copied().unwrap_or_default()
+	}
+
+	/// How many users there are on all the platforms
+	#[must_use]
+	pub fn total(&self) -> u32 { self.0.values().sum() }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
@@ -106,10 +172,45 @@ pub struct Instance {
 	#[serde(rename = "type")]
 	/// Who can join the instance, also called instance type
 	pub privacy: InstancePrivacy,
+	/// Who can join the instance if it's a group instance
+	#[serde(default)]
+	pub group_access_type: Option<GroupAccessType>,
+	/// If joining the group instance is limited to some roles
+	#[serde(default)]
+	pub role_restricted: Option<bool>,
+	/// If users join a queue when the instance is full
+	#[serde(default)]
+	pub queue_enabled: Option<bool>,
+	/// If only age verified users can join the instance
+	#[serde(default)]
+	pub age_gate: Option<bool>,
 	/// The ID of the world that the instance is running
 	pub world_id: crate::id::World,
 	/// An ID of the world creator that only exists if current user is treated
 	/// as the creator
 	#[serde(flatten, default)]
 	pub creator_details: Option<InstanceCreatorId>,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+#[cfg(test)]
+#[test]
+fn platform_user_counter() {
+	use super::Platform;
+
+	let counter: InstancePlatformUserCounter =
+		serde_json::from_value(serde_json::json!({
+			"standalonewindows": 12,
+			"android": 5,
+			"ios": 2,
+			"newplatform": 1,
+		}))
+		.unwrap();
+
+	assert_eq!(counter.count(&Platform::StandaloneWindows), 12);
+	assert_eq!(counter.count(&Platform::Ios), 2);
+	assert_eq!(counter.count(&Platform::Unknown("newplatform".to_owned())), 1);
+	assert_eq!(counter.total(), 20);
 }
@@ -20,11 +20,63 @@ mod users;
 use serde::{Deserialize, Serialize};
 pub use users::*;
 
+/// A platform that VRC runs on
+#[derive(
+	Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Platform {
+	/// Windows, also known as PC
+	StandaloneWindows,
+	/// Android, also known as Quest
+	Android,
+	/// iOS
+	Ios,
+	/// Possible other values that may get added in the future,
+	/// the API sometimes also gives random Unity versions
+	#[serde(untagged)]
+	Unknown(String),
+}
+
+impl AsRef<str> for Platform {
+	fn as_ref(&self) -> &str {
+		match self {
+			Self::StandaloneWindows => "standalonewindows",
+			Self::Android => "android",
+			Self::Ios => "ios",
+			Self::Unknown(v) => v,
+		}
+	}
+}
+
+impl std::fmt::Display for Platform {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		f.write_str(self.as_ref())
+	}
+}
+
+/// Infallible, unrecognized platforms become [`Platform::Unknown`]
+impl std::str::FromStr for Platform {
+	type Err = std::convert::Infallible;
+
+	fn from_str(platform: &str) -> Result<Self, Self::Err> {
+		Ok(match platform {
+			"standalonewindows" => Self::StandaloneWindows,
+			"android" => Self::Android,
+			"ios" => Self::Ios,
+			_ => Self::Unknown(platform.to_owned()),
+		})
+	}
+}
+
 /// A generic success status response
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub struct SuccessResponse {
 	message: String,
 	status_code: u16,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
 }
 
 /// A very generic response to some operations
@@ -7,8 +7,8 @@ use crate::id::{OfflineOr, OfflineOrPrivateOr};
 
 #[derive(
 	Clone,
-	Copy,
 	Debug,
+	Default,
 	Eq,
 	PartialEq,
 	Ord,
@@ -16,12 +16,12 @@ use crate::id::{OfflineOr, OfflineOrPrivateOr};
 	Hash,
 	Serialize,
 	Deserialize,
-	strum::AsRefStr,
 )]
 #[serde(rename_all = "camelCase")]
 /// If the user has some special status
 pub enum DeveloperType {
 	/// No special status
+	#[default]
 	None,
 	/// The user is trusted by VRC developers?
 	Trusted,
@@ -29,16 +29,27 @@ pub enum DeveloperType {
 	Internal,
 	/// The user is a moderator
 	Moderator,
+	/// Possible other values that may get added in the future
+	#[serde(untagged)]
+	Unknown(String),
 }
 
-impl Default for DeveloperType {
-	fn default() -> Self { Self::None }
+impl AsRef<str> for DeveloperType {
+	fn as_ref(&self) -> &str {
+		match self {
+			Self::None => "none",
+			Self::Trusted => "trusted",
+			Self::Internal => "internal",
+			Self::Moderator => "moderator",
+			Self::Unknown(v) => v,
+		}
+	}
 }
 
 #[derive(
 	Clone,
-	Copy,
 	Debug,
+	Default,
 	Eq,
 	PartialEq,
 	Ord,
@@ -46,35 +57,35 @@ impl Default for DeveloperType {
 	Hash,
 	Serialize,
 	Deserialize,
-	strum::AsRefStr,
 )]
 #[serde(rename_all = "camelCase")]
 /// If the user is offline or not
 pub enum UserState {
 	/// The user is offline
+	#[default]
 	Offline,
 	/// The user is on the website for example
 	Active,
 	/// User is online on VRC
 	Online,
+	/// Possible other values that may get added in the future
+	#[serde(untagged)]
+	Unknown(String),
 }
 
-impl Default for UserState {
-	fn default() -> Self { Self::Offline }
+impl AsRef<str> for UserState {
+	fn as_ref(&self) -> &str {
+		match self {
+			Self::Offline => "offline",
+			Self::Active => "active",
+			Self::Online => "online",
+			Self::Unknown(v) => v,
+		}
+	}
 }
 
 #[derive(
-	Clone,
-	Copy,
-	Debug,
-	Eq,
-	PartialEq,
-	Ord,
-	PartialOrd,
-	Hash,
-	Serialize,
-	Deserialize,
-	strum::AsRefStr,
+	Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize,
 )]
 #[serde(rename_all = "camelCase")]
 /// The status of an user
@@ -91,20 +102,26 @@ pub enum UserStatus {
 	AskMe,
 	/// Also known as red
 	Busy,
+	/// Possible other values that may get added in the future
+	#[serde(untagged)]
+	Unknown(String),
+}
+
+impl AsRef<str> for UserStatus {
+	fn as_ref(&self) -> &str {
+		match self {
+			Self::Offline => "offline",
+			Self::Active => "active",
+			Self::JoinMe => "join me",
+			Self::AskMe => "ask me",
+			Self::Busy => "busy",
+			Self::Unknown(v) => v,
+		}
+	}
 }
 
 #[derive(
-	Clone,
-	Copy,
-	Debug,
-	Eq,
-	PartialEq,
-	Ord,
-	PartialOrd,
-	Hash,
-	Serialize,
-	Deserialize,
-	strum::AsRefStr,
+	Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize,
 )]
 #[serde(rename_all = "camelCase")]
 /// The status of an user
@@ -113,9 +130,23 @@ pub enum FriendRequestStatus {
 	Incoming,
 	/// Also known as orange
 	Outgoing,
-	/// No pending friend requests (or serialization failed...)
-	#[serde(other)]
-	Other,
+	/// No pending friend requests
+	#[serde(rename = "")]
+	None,
+	/// Possible other values that may get added in the future
+	#[serde(untagged)]
+	Unknown(String),
+}
+
+impl AsRef<str> for FriendRequestStatus {
+	fn as_ref(&self) -> &str {
+		match self {
+			Self::Incoming => "incoming",
+			Self::Outgoing => "outgoing",
+			Self::None => "",
+			Self::Unknown(v) => v,
+		}
+	}
 }
 
 #[serde_with::serde_as]
@@ -142,10 +173,9 @@ pub struct Presence {
 	/// Can be empty string if missing.
 	pub instance_type: String,
 	#[serde(default)]
-	/// The platform that the user is on.
-	///
-	/// Can be empty string if missing.
-	pub platform: String,
+	#[serde_as(as = "serde_with::NoneAsEmptyString")]
+	/// The platform that the user is on
+	pub platform: Option<super::Platform>,
 	/// The user's own picture to replace the avatar pic with
 	#[serde(default)]
 	#[serde_as(as = "serde_with::NoneAsEmptyString")]
@@ -171,6 +201,9 @@ pub struct Presence {
 	/// What does this do?
 	#[serde(default)]
 	pub debugflag: serde_json::Value,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
@@ -185,6 +218,9 @@ pub struct PastDisplayName {
 	/// If the display name change has been reverted
 	#[serde(default)]
 	pub reverted: bool,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
 }
 
 #[serde_with::serde_as]
@@ -219,11 +255,10 @@ pub struct AccountData {
 	pub id: crate::id::User,
 	/// If the user is a friend of the currently authenticated user
 	pub is_friend: bool,
-	// TODO: Platform enum
-	/// This can be `standalonewindows` or `android`, but can also pretty much
-	/// be any random Unity version or even `unknownplatform`.
+	/// The platform that the user was last on, which can also pretty much be
+	/// any random Unity version or even `unknownplatform`.
 	#[serde(rename = "last_platform")]
-	pub last_platform: String,
+	pub last_platform: super::Platform,
 	/// Possible profile picture URL
 	#[serde(default)]
 	#[serde_as(as = "serde_with::NoneAsEmptyString")]
@@ -238,6 +273,200 @@ pub struct AccountData {
 	#[serde(default)]
 	#[serde_as(as = "serde_with::NoneAsEmptyString")]
 	pub user_icon: Option<Url>,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+impl AccountData {
+	/// Parses the tags of the user
+	pub fn user_tags(&self) -> impl Iterator<Item = UserTag> + '_ {
+		self.tags.iter().map(|tag| UserTag::from(tag.as_str()))
+	}
+
+	/// The trust rank of the user, as derived from their tags
+	#[must_use]
+	pub fn trust_rank(&self) -> TrustRank {
+		TrustRank::from_tags(self.user_tags())
+	}
+
+	/// If the user has VRC+
+	#[must_use]
+	pub fn is_vrc_plus(&self) -> bool {
+		self.user_tags().any(|tag| tag == UserTag::Supporter)
+	}
+
+	/// The ISO 639-3 codes of the languages that the user speaks, like `eng`
+	pub fn languages(&self) -> impl Iterator<Item = &str> {
+		self.tags.iter().filter_map(|tag| tag.strip_prefix("language_"))
+	}
+}
+
+#[derive(
+	Clone,
+	Debug,
+	Eq,
+	PartialEq,
+	Hash,
+	serde_with::SerializeDisplay,
+	serde_with::DeserializeFromStr,
+)]
+/// A tag of a user, like `system_trust_known`
+pub enum UserTag {
+	/// A trust rank, `system_trust_*`
+	Trust(TrustTag),
+	/// Nuisance that has been confirmed, `system_troll`
+	Troll,
+	/// Possible nuisance, `system_probable_troll`
+	ProbableTroll,
+	/// Has VRC+, `system_supporter`
+	Supporter,
+	/// Has had VRC+ since early on, `system_early_adopter`
+	EarlyAdopter,
+	/// Can upload avatars, `system_avatar_access`
+	AvatarAccess,
+	/// Can upload worlds, `system_world_access`
+	WorldAccess,
+	/// A language that the user speaks, `language_*` with the ISO 639-3 code
+	Language(String),
+	/// A tag set by the VRC team, `admin_*` without the prefix
+	Admin(String),
+	/// Possible other values that may get added in the future
+	Unknown(String),
+}
+
+impl From<&str> for UserTag {
+	fn from(tag: &str) -> Self {
+		if let Some(language) = tag.strip_prefix("language_") {
+			return Self::Language(language.to_owned());
+		}
+		if let Some(admin) = tag.strip_prefix("admin_") {
+			return Self::Admin(admin.to_owned());
+		}
+
+		match tag {
+			"system_trust_basic" => Self::Trust(TrustTag::Basic),
+			"system_trust_known" => Self::Trust(TrustTag::Known),
+			"system_trust_trusted" => Self::Trust(TrustTag::Trusted),
+			"system_trust_veteran" => Self::Trust(TrustTag::Veteran),
+			"system_trust_legend" => Self::Trust(TrustTag::Legend),
+			"system_troll" => Self::Troll,
+			"system_probable_troll" => Self::ProbableTroll,
+			"system_supporter" => Self::Supporter,
+			"system_early_adopter" => Self::EarlyAdopter,
+			"system_avatar_access" => Self::AvatarAccess,
+			"system_world_access" => Self::WorldAccess,
+			_ => Self::Unknown(tag.to_owned()),
+		}
+	}
+}
+
+/// Infallible, unrecognized tags become [`UserTag::Unknown`]
+impl std::str::FromStr for UserTag {
+	type Err = std::convert::Infallible;
+
+	fn from_str(tag: &str) -> Result<Self, Self::Err> { Ok(Self::from(tag)) }
+}
+
+impl std::fmt::Display for UserTag {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self {
+			Self::Trust(trust) => match trust {
+				TrustTag::Basic => f.write_str("system_trust_basic"),
+				TrustTag::Known => f.write_str("system_trust_known"),
+				TrustTag::Trusted => f.write_str("system_trust_trusted"),
+				TrustTag::Veteran => f.write_str("system_trust_veteran"),
+				TrustTag::Legend => f.write_str("system_trust_legend"),
+			},
+			Self::Troll => f.write_str("system_troll"),
+			Self::ProbableTroll => f.write_str("system_probable_troll"),
+			Self::Supporter => f.write_str("system_supporter"),
+			Self::EarlyAdopter => f.write_str("system_early_adopter"),
+			Self::AvatarAccess => f.write_str("system_avatar_access"),
+			Self::WorldAccess => f.write_str("system_world_access"),
+			Self::Language(language) => write!(f, "language_{language}"),
+			Self::Admin(admin) => write!(f, "admin_{admin}"),
+			Self::Unknown(tag) => f.write_str(tag),
+		}
+	}
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+/// A trust tag of a user, which are the trust ranks that have their own tag
+pub enum TrustTag {
+	/// `system_trust_basic`
+	Basic,
+	/// `system_trust_known`
+	Known,
+	/// `system_trust_trusted`
+	Trusted,
+	/// `system_trust_veteran`
+	Veteran,
+	/// `system_trust_legend`
+	Legend,
+}
+
+impl From<TrustTag> for TrustRank {
+	fn from(tag: TrustTag) -> Self {
+		match tag {
+			TrustTag::Basic => Self::NewUser,
+			TrustTag::Known => Self::User,
+			TrustTag::Trusted => Self::KnownUser,
+			TrustTag::Veteran => Self::TrustedUser,
+			TrustTag::Legend => Self::Legendary,
+		}
+	}
+}
+
+#[derive(
+	Clone,
+	Copy,
+	Debug,
+	Default,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	Hash,
+	Serialize,
+	Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+/// The trust rank of a user, from the least to the most trusted
+pub enum TrustRank {
+	/// Has been marked as a troll or a probable troll
+	Nuisance,
+	/// Has no trust tags
+	#[default]
+	Visitor,
+	/// Also known as blue, `system_trust_basic`
+	NewUser,
+	/// Also known as green, `system_trust_known`
+	User,
+	/// Also known as orange, `system_trust_trusted`
+	KnownUser,
+	/// Also known as purple, `system_trust_veteran`
+	TrustedUser,
+	/// No longer given out, `system_trust_legend`
+	Legendary,
+}
+
+impl TrustRank {
+	/// Derives the trust rank from the tags of a user.
+	///
+	/// Users get all the lower trust tags as well,
+	/// so the highest one is used, unless they've been marked as a nuisance.
+	pub fn from_tags(tags: impl IntoIterator<Item = UserTag>) -> Self {
+		let mut rank = Self::Visitor;
+		for tag in tags {
+			match tag {
+				UserTag::Troll | UserTag::ProbableTroll => return Self::Nuisance,
+				UserTag::Trust(tag) => rank = rank.max(tag.into()),
+				_ => {}
+			}
+		}
+		rank
+	}
 }
 
 /// Details that get added if the user is the authenticated one
@@ -353,7 +582,7 @@ pub struct StatusData {
 /// Data that's returned from the users endpoint about friends,
 /// but not for authenticated user
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all = "camelCase", from = "FlatFriendUserStatusData")]
 pub struct FriendUserStatusData {
 	/// The base status data
 	#[serde(flatten)]
@@ -366,13 +595,34 @@ pub struct FriendUserStatusData {
 	pub traveling_to_world: OfflineOrPrivateOr<crate::id::World>,
 }
 
+/// Deserializing without nested flattening, so that the fields get consumed
+/// when this is flattened into another model
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FlatFriendUserStatusData {
+	state: UserState,
+	#[serde(rename = "last_activity", with = "rfc3339")]
+	last_activity: OffsetDateTime,
+	traveling_to_location: OfflineOrPrivateOr<String>,
+	traveling_to_instance: OfflineOrPrivateOr<crate::id::Instance>,
+	traveling_to_world: OfflineOrPrivateOr<crate::id::World>,
+}
+
+impl From<FlatFriendUserStatusData> for FriendUserStatusData {
+	fn from(flat: FlatFriendUserStatusData) -> Self {
+		Self {
+			base: StatusData { state: flat.state, last_activity: flat.last_activity },
+			traveling_to_location: flat.traveling_to_location,
+			traveling_to_instance: flat.traveling_to_instance,
+			traveling_to_world: flat.traveling_to_world,
+		}
+	}
+}
+
 /// Extended details about the current user
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CurrentAccount {
-	/// Base info that's shared across different user responses
-	#[serde(flatten)]
-	pub base: AccountData,
 	#[serde(flatten)]
 	/// Details about the current user as an authenticated account
 	pub current: CurrentAccountData,
@@ -382,6 +632,10 @@ pub struct CurrentAccount {
 	/// Seems to always be set
 	#[serde(flatten)]
 	pub friend_data: FriendData,
+	// Flattened last, as it captures the unknown fields
+	/// Base info that's shared across different user responses
+	#[serde(flatten)]
+	pub base: AccountData,
 	/// The presence of self
 	pub presence: Presence,
 	/// Friends that are offline
@@ -405,9 +659,6 @@ pub struct CurrentAccount {
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Friend {
-	/// Base info that's shared across different user responses
-	#[serde(flatten)]
-	pub base: AccountData,
 	/// Data about the friend
 	#[serde(flatten)]
 	pub friend: FriendData,
@@ -415,6 +666,10 @@ pub struct Friend {
 	/// user
 	#[serde(flatten)]
 	pub user_or_friend: UserOrFriendData,
+	// Flattened last, as it captures the unknown fields
+	/// Base info that's shared across different user responses
+	#[serde(flatten)]
+	pub base: AccountData,
 	// The fallback avatar's ID
 	//pub fallback_avatar: crate::id::Avatar,
 	/// The user's image
@@ -425,13 +680,14 @@ pub struct Friend {
 #[serde(rename_all = "camelCase")]
 /// Details about a VRC user
 pub struct User {
-	/// Base info that's shared across different user responses
-	#[serde(flatten)]
-	pub base: AccountData,
 	/// Data that's returned for user and friend endpoints but not from current
 	/// user
 	#[serde(flatten)]
 	pub user_or_friend: UserOrFriendData,
+	// Flattened last, as it captures the unknown fields
+	/// Base info that's shared across different user responses
+	#[serde(flatten)]
+	pub base: AccountData,
 	/// If the user has avatar cloning on
 	pub allow_avatar_copying: bool,
 	/// When the user joined VRC
@@ -451,9 +707,6 @@ pub struct User {
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CurrentUser {
-	/// Base user info
-	#[serde(flatten)]
-	pub base: User,
 	#[serde(flatten)]
 	/// Extended details show due to authentication
 	pub account: CurrentAccountData,
@@ -463,6 +716,10 @@ pub struct CurrentUser {
 	#[serde(flatten)]
 	/// Data that's shared with friends response
 	pub friend: FriendData,
+	// Flattened last, as it captures the unknown fields
+	/// Base user info
+	#[serde(flatten)]
+	pub base: User,
 	/// The fallback avatar
 	pub fallback_avatar: crate::id::Avatar,
 }
@@ -472,15 +729,16 @@ pub struct CurrentUser {
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FriendUser {
-	/// Base user data
-	#[serde(flatten)]
-	pub base: User,
 	#[serde(flatten)]
 	/// Status data that's also shown about friends
 	pub status: FriendUserStatusData,
 	#[serde(flatten)]
 	/// Data that's shared with friends response
 	pub friend: FriendData,
+	// Flattened last, as it captures the unknown fields
+	/// Base user data
+	#[serde(flatten)]
+	pub base: User,
 }
 
 /// Data that's returned from the user endpoint
@@ -536,3 +794,154 @@ impl AnyUser {
 		}
 	}
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+/// The friendship status between the authenticated user and another user
+pub struct FriendStatus {
+	/// If the users are friends
+	pub is_friend: bool,
+	/// If the authenticated user has sent a friend request to the user
+	pub outgoing_request: bool,
+	/// If the user has sent a friend request to the authenticated user
+	pub incoming_request: bool,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+impl FriendStatus {
+	#[must_use]
+	/// The pending friend request as it's shown in the user details
+	pub const fn request_status(&self) -> FriendRequestStatus {
+		if self.incoming_request {
+			FriendRequestStatus::Incoming
+		} else if self.outgoing_request {
+			FriendRequestStatus::Outgoing
+		} else {
+			FriendRequestStatus::None
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+/// A private note that the authenticated user has written about another user
+pub struct UserNote {
+	/// The ID of the note
+	pub id: crate::id::UserNote,
+	/// The ID of the user who wrote the note
+	pub user_id: crate::id::User,
+	/// The ID of the user that the note is about
+	pub target_user_id: crate::id::User,
+	/// The note itself
+	pub note: String,
+	/// When the note was written
+	#[serde(with = "rfc3339")]
+	pub created_at: OffsetDateTime,
+	/// A summary of the user that the note is about
+	#[serde(default)]
+	pub target_user: Option<UserNoteTarget>,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+/// A summary of the user that a note is about
+pub struct UserNoteTarget {
+	/// The ID of the user
+	pub id: crate::id::User,
+	/// The display name of the user
+	pub display_name: String,
+	/// The avatar's smaller image, can be an empty string
+	#[serde(default)]
+	#[serde_as(as = "serde_with::NoneAsEmptyString")]
+	pub current_avatar_thumbnail_image_url: Option<Url>,
+	/// The current avatar tags
+	#[serde(default)]
+	pub current_avatar_tags: Vec<String>,
+	/// URL to the user's profile picture, can be an empty string
+	#[serde(default)]
+	#[serde_as(as = "serde_with::NoneAsEmptyString")]
+	pub profile_pic_override: Option<Url>,
+	/// URL to the user's icon, can be an empty string
+	#[serde(default)]
+	#[serde_as(as = "serde_with::NoneAsEmptyString")]
+	pub user_icon: Option<Url>,
+	/// Fields that weren't recognized when deserializing
+	#[serde(flatten)]
+	pub extra: crate::extra::Extra<Self>,
+}
+
+#[cfg(test)]
+#[test]
+fn unknown_enum_values() {
+	let status: UserStatus = serde_json::from_str("\"join me\"").unwrap();
+	assert_eq!(status, UserStatus::JoinMe);
+	assert_eq!(status.as_ref(), "join me");
+
+	let status: UserStatus = serde_json::from_str("\"do not disturb\"").unwrap();
+	assert_eq!(status, UserStatus::Unknown("do not disturb".to_owned()));
+	assert_eq!(status.as_ref(), "do not disturb");
+	assert_eq!(serde_json::to_string(&status).unwrap(), "\"do not disturb\"");
+
+	let status: FriendRequestStatus = serde_json::from_str("\"\"").unwrap();
+	assert_eq!(status, FriendRequestStatus::None);
+	assert_eq!(serde_json::to_string(&status).unwrap(), "\"\"");
+
+	let status: FriendRequestStatus = serde_json::from_str("\"muted\"").unwrap();
+	assert_eq!(status, FriendRequestStatus::Unknown("muted".to_owned()));
+}
+
+#[cfg(test)]
+#[test]
+fn friend_request_status() {
+	let status: FriendStatus = serde_json::from_value(serde_json::json!({
+		"isFriend": false,
+		"outgoingRequest": false,
+		"incomingRequest": false,
+	}))
+	.unwrap();
+	assert_eq!(status.request_status(), FriendRequestStatus::None);
+
+	let status: FriendStatus = serde_json::from_value(serde_json::json!({
+		"isFriend": false,
+		"outgoingRequest": true,
+		"incomingRequest": false,
+	}))
+	.unwrap();
+	assert_eq!(status.request_status(), FriendRequestStatus::Outgoing);
+}
+
+#[cfg(test)]
+#[test]
+fn user_tags() {
+	let tags = ["system_trust_basic", "system_trust_known", "language_eng"];
+	assert_eq!(TrustRank::from_tags(tags.map(UserTag::from)), TrustRank::User);
+	assert_eq!(
+		TrustRank::from_tags(
+			["system_trust_veteran", "system_probable_troll"].map(UserTag::from)
+		),
+		TrustRank::Nuisance
+	);
+	assert!(TrustRank::Visitor < TrustRank::NewUser);
+
+	for tag in [
+		"system_trust_basic",
+		"system_trust_veteran",
+		"system_troll",
+		"language_jpn",
+		"admin_moderator",
+		"x",
+	] {
+		assert_eq!(UserTag::from(tag).to_string(), tag);
+	}
+	assert_eq!(
+		UserTag::from("system_trust_legend"),
+		UserTag::Trust(TrustTag::Legend)
+	);
+	assert_eq!(TrustRank::from(TrustTag::Known), TrustRank::User);
+}
@@ -0,0 +1,146 @@
+use racal::Queryable;
+use serde::{Deserialize, Serialize};
+
+use super::{Authentication, Pagination};
+use crate::model::{Platform, ReleaseStatus};
+
+/// The changes to make to an avatar, unset fields are left as is
+#[derive(
+	Default, Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct AvatarUpdate {
+	/// The name of the avatar
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
+	/// The description of the avatar
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub description: Option<String>,
+	/// The tags of the avatar, replacing all the current ones
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub tags: Option<Vec<String>>,
+	/// The release status of the avatar
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub release_status: Option<ReleaseStatus>,
+	/// An image for displaying the avatar
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub image_url: Option<url::Url>,
+}
+
+/// Updates an avatar of the current user
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct UpdateAvatar {
+	/// The ID of the avatar to update
+	pub id: crate::id::Avatar,
+	/// The changes to make
+	pub update: AvatarUpdate,
+}
+
+impl Queryable<Authentication, crate::model::Avatar> for UpdateAvatar {
+	fn url(&self, _: &Authentication) -> String {
+		format!("{}/avatars/{}", crate::API_BASE_URI, self.id.as_ref())
+	}
+
+	fn body(
+		&self, _state: &Authentication,
+	) -> Option<serde_json::Result<Vec<u8>>> {
+		Some(serde_json::to_vec(&self.update))
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Put
+	}
+}
+
+/// Deletes an avatar of the current user
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct DeleteAvatar {
+	/// The ID of the avatar to delete
+	pub id: crate::id::Avatar,
+}
+
+impl Queryable<Authentication, ()> for DeleteAvatar {
+	fn url(&self, _: &Authentication) -> String {
+		format!("{}/avatars/{}", crate::API_BASE_URI, self.id.as_ref())
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Delete
+	}
+
+	fn deserialize(&self, _data: &[u8]) -> serde_json::Result<()> { Ok(()) }
+}
+
+/// Lists the avatars of the current user
+#[derive(
+	Default, Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize,
+)]
+pub struct ListOwnAvatars {
+	/// If to filter based on the release status, lists all the avatars if not
+	pub release_status: Option<ReleaseStatus>,
+	/// If to filter based on the supported platform
+	pub platform: Option<Platform>,
+	/// The pagination for the query
+	#[serde(flatten)]
+	pub pagination: Pagination,
+}
+
+impl Queryable<Authentication, Vec<crate::model::Avatar>> for ListOwnAvatars {
+	fn url(&self, _: &Authentication) -> String {
+		let mut query = format!(
+			"{}/avatars?user=me&{}&releaseStatus={}",
+			crate::API_BASE_URI,
+			self.pagination.to_query_str(),
+			self.release_status.as_ref().map_or("all", AsRef::as_ref),
+		);
+
+		if let Some(platform) = &self.platform {
+			query.push_str("&platform=");
+			query.push_str(platform.as_ref());
+		}
+
+		query
+	}
+}
+
+/// Starts generating the impostor of an avatar of the current user
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct GenerateAvatarImpostor {
+	/// The ID of the avatar to generate the impostor for
+	pub id: crate::id::Avatar,
+}
+
+impl Queryable<Authentication, ()> for GenerateAvatarImpostor {
+	fn url(&self, _: &Authentication) -> String {
+		format!(
+			"{}/avatars/{}/impostor/enqueue",
+			crate::API_BASE_URI,
+			self.id.as_ref()
+		)
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Post
+	}
+
+	fn deserialize(&self, _data: &[u8]) -> serde_json::Result<()> { Ok(()) }
+}
+
+/// Deletes the impostor of an avatar of the current user
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct DeleteAvatarImpostor {
+	/// The ID of the avatar to delete the impostor of
+	pub id: crate::id::Avatar,
+}
+
+impl Queryable<Authentication, ()> for DeleteAvatarImpostor {
+	fn url(&self, _: &Authentication) -> String {
+		format!("{}/avatars/{}/impostor", crate::API_BASE_URI, self.id.as_ref())
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Delete
+	}
+
+	fn deserialize(&self, _data: &[u8]) -> serde_json::Result<()> { Ok(()) }
+}
@@ -33,3 +33,73 @@ impl Queryable<Authentication, Vec<crate::model::Friend>> for ListFriends {
 		query
 	}
 }
+
+/// Sends a friend request to a user
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct SendFriendRequest {
+	/// The ID of the user to send the friend request to
+	pub id: crate::id::User,
+}
+
+impl Queryable<Authentication, ()> for SendFriendRequest {
+	fn url(&self, _: &Authentication) -> String {
+		format!("{}/user/{}/friendRequest", crate::API_BASE_URI, self.id.as_ref())
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Post
+	}
+
+	fn deserialize(&self, _data: &[u8]) -> serde_json::Result<()> { Ok(()) }
+}
+
+/// Cancels a pending outgoing friend request to a user
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct CancelFriendRequest {
+	/// The ID of the user that the friend request was sent to
+	pub id: crate::id::User,
+}
+
+impl Queryable<Authentication, ()> for CancelFriendRequest {
+	fn url(&self, _: &Authentication) -> String {
+		format!("{}/user/{}/friendRequest", crate::API_BASE_URI, self.id.as_ref())
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Delete
+	}
+
+	fn deserialize(&self, _data: &[u8]) -> serde_json::Result<()> { Ok(()) }
+}
+
+/// Removes a user from the friends list
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct Unfriend {
+	/// The ID of the friend to remove
+	pub id: crate::id::User,
+}
+
+impl Queryable<Authentication, ()> for Unfriend {
+	fn url(&self, _: &Authentication) -> String {
+		format!("{}/auth/user/friends/{}", crate::API_BASE_URI, self.id.as_ref())
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Delete
+	}
+
+	fn deserialize(&self, _data: &[u8]) -> serde_json::Result<()> { Ok(()) }
+}
+
+/// Gets the friendship status with a user
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct FriendStatus {
+	/// The ID of the user to get the friendship status with
+	pub id: crate::id::User,
+}
+
+impl Queryable<Authentication, crate::model::FriendStatus> for FriendStatus {
+	fn url(&self, _: &Authentication) -> String {
+		format!("{}/user/{}/friendStatus", crate::API_BASE_URI, self.id.as_ref())
+	}
+}
@@ -1,7 +1,16 @@
+use std::collections::BTreeSet;
+
 use racal::Queryable;
 use serde::{Deserialize, Serialize};
+use strum::AsRefStr;
 
 use super::{Authentication, Pagination};
+use crate::model::{
+	GroupCode,
+	GroupMemberVisibility,
+	GroupPermission,
+	GroupPostVisibility,
+};
 
 /// Gets information about a specific group
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
@@ -49,7 +58,9 @@ pub struct GroupBans {
 	pub pagination: Pagination,
 }
 
-impl Queryable<Authentication, Vec<crate::model::GroupBan>> for GroupBans {
+impl Queryable<Authentication, Vec<crate::model::FullGroupMember>>
+	for GroupBans
+{
 	fn url(&self, _: &Authentication) -> String {
 		format!(
 			"{}/groups/{}/bans?{}",
@@ -69,9 +80,9 @@ pub struct GroupBan {
 	pub user_id: crate::id::User,
 }
 
-impl Queryable<Authentication, crate::model::GroupBan> for GroupBan {
+impl Queryable<Authentication, crate::model::FullGroupMember> for GroupBan {
 	fn url(&self, _: &Authentication) -> String {
-		format!("{}/groups/{}/bans", crate::API_BASE_URI, self.group_id.as_ref(),)
+		format!("{}/groups/{}/bans", crate::API_BASE_URI, self.group_id.as_ref())
 	}
 
 	fn body(
@@ -96,7 +107,7 @@ pub struct GroupUnban {
 	pub user_id: crate::id::User,
 }
 
-impl Queryable<Authentication, crate::model::GroupBan> for GroupUnban {
+impl Queryable<Authentication, crate::model::FullGroupMember> for GroupUnban {
 	fn url(&self, _: &Authentication) -> String {
 		format!(
 			"{}/groups/{}/bans/{}",
@@ -121,7 +132,7 @@ pub struct GroupMember {
 	pub user_id: crate::id::User,
 }
 
-impl Queryable<Authentication, Option<crate::model::GroupMember>>
+impl Queryable<Authentication, Option<crate::model::LimitedGroupMember>>
 	for GroupMember
 {
 	fn url(&self, _: &Authentication) -> String {
@@ -133,3 +144,1045 @@ impl Queryable<Authentication, Option<crate::model::GroupMember>>
 		)
 	}
 }
+
+/// The sorting for listing group members
+#[derive(
+	Debug,
+	Default,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Hash,
+	Deserialize,
+	Serialize,
+	AsRefStr,
+)]
+pub enum GroupMembersSort {
+	/// Members who joined first are first
+	#[serde(rename = "joinedAt:asc")]
+	#[strum(serialize = "joinedAt:asc")]
+	JoinedAtAscending,
+	/// Members who joined last are first
+	#[default]
+	#[serde(rename = "joinedAt:desc")]
+	#[strum(serialize = "joinedAt:desc")]
+	JoinedAtDescending,
+}
+
+/// Lists the members of a group
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct ListGroupMembers {
+	/// The ID of the group
+	pub id: crate::id::Group,
+	/// The sorting of the members
+	pub sort: GroupMembersSort,
+	/// If to only list members that have a specific role
+	pub role_id: Option<crate::id::GroupRole>,
+	/// Limits how many results are returned
+	#[serde(flatten)]
+	pub pagination: Pagination,
+}
+
+impl Queryable<Authentication, Vec<crate::model::FullGroupMember>>
+	for ListGroupMembers
+{
+	fn url(&self, _: &Authentication) -> String {
+		let mut query = format!(
+			"{}/groups/{}/members?{}&sort={}",
+			crate::API_BASE_URI,
+			self.id.as_ref(),
+			self.pagination.to_query_str(),
+			self.sort.as_ref()
+		);
+
+		if let Some(role_id) = &self.role_id {
+			query.push_str("&roleId=");
+			query.push_str(role_id.as_ref());
+		}
+
+		query
+	}
+}
+
+/// Kicks a user from a group
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct KickGroupMember {
+	/// The ID of the group
+	pub group_id: crate::id::Group,
+	/// The ID of the user to kick
+	pub user_id: crate::id::User,
+}
+
+impl Queryable<Authentication, ()> for KickGroupMember {
+	fn url(&self, _: &Authentication) -> String {
+		format!(
+			"{}/groups/{}/members/{}",
+			crate::API_BASE_URI,
+			self.group_id.as_ref(),
+			self.user_id.as_ref()
+		)
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Delete
+	}
+
+	fn deserialize(&self, _data: &[u8]) -> serde_json::Result<()> { Ok(()) }
+}
+
+/// The changes to make to a group member, unset fields are left as is
+#[derive(
+	Default, Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupMemberUpdate {
+	/// The visibility of the membership
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub visibility: Option<GroupMemberVisibility>,
+	/// If the member should get notified about group announcements
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub is_subscribed_to_announcements: Option<bool>,
+	/// Notes made by the managers of the group about the member
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub manager_notes: Option<String>,
+}
+
+/// Updates the details of a group member
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct UpdateGroupMember {
+	/// The ID of the group
+	pub group_id: crate::id::Group,
+	/// The ID of the user to update
+	pub user_id: crate::id::User,
+	/// The changes to make
+	pub update: GroupMemberUpdate,
+}
+
+impl Queryable<Authentication, crate::model::FullGroupMember>
+	for UpdateGroupMember
+{
+	fn url(&self, _: &Authentication) -> String {
+		format!(
+			"{}/groups/{}/members/{}",
+			crate::API_BASE_URI,
+			self.group_id.as_ref(),
+			self.user_id.as_ref()
+		)
+	}
+
+	fn body(
+		&self, _state: &Authentication,
+	) -> Option<serde_json::Result<Vec<u8>>> {
+		Some(serde_json::to_vec(&self.update))
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Put
+	}
+}
+
+/// Joins a group, or requests to join it if the group requires it
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct JoinGroup {
+	/// The ID of the group to join
+	pub id: crate::id::Group,
+}
+
+impl Queryable<Authentication, crate::model::LimitedGroupMember> for JoinGroup {
+	fn url(&self, _: &Authentication) -> String {
+		format!("{}/groups/{}/join", crate::API_BASE_URI, self.id.as_ref())
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Post
+	}
+}
+
+/// Leaves a group
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct LeaveGroup {
+	/// The ID of the group to leave
+	pub id: crate::id::Group,
+}
+
+impl Queryable<Authentication, ()> for LeaveGroup {
+	fn url(&self, _: &Authentication) -> String {
+		format!("{}/groups/{}/leave", crate::API_BASE_URI, self.id.as_ref())
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Post
+	}
+
+	fn deserialize(&self, _data: &[u8]) -> serde_json::Result<()> { Ok(()) }
+}
+
+/// Cancels a pending request to join a group
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct CancelGroupJoinRequest {
+	/// The ID of the group that the request was sent to
+	pub id: crate::id::Group,
+}
+
+impl Queryable<Authentication, ()> for CancelGroupJoinRequest {
+	fn url(&self, _: &Authentication) -> String {
+		format!("{}/groups/{}/requests", crate::API_BASE_URI, self.id.as_ref())
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Delete
+	}
+
+	fn deserialize(&self, _data: &[u8]) -> serde_json::Result<()> { Ok(()) }
+}
+
+/// Lists the pending requests to join a group
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct ListGroupRequests {
+	/// The ID of the group
+	pub id: crate::id::Group,
+	/// If to list the blocked requests instead
+	pub blocked: bool,
+	/// Limits how many results are returned
+	#[serde(flatten)]
+	pub pagination: Pagination,
+}
+
+impl Queryable<Authentication, Vec<crate::model::FullGroupMember>>
+	for ListGroupRequests
+{
+	fn url(&self, _: &Authentication) -> String {
+		format!(
+			"{}/groups/{}/requests?{}&blocked={}",
+			crate::API_BASE_URI,
+			self.id.as_ref(),
+			self.pagination.to_query_str(),
+			self.blocked
+		)
+	}
+}
+
+/// How to respond to a request to join a group
+#[derive(
+	Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize, AsRefStr,
+)]
+#[serde(rename_all = "camelCase")]
+#[strum(serialize_all = "camelCase")]
+pub enum GroupJoinRequestAction {
+	/// Lets the user join the group
+	Accept,
+	/// Denies the user from joining the group
+	Reject,
+}
+
+/// Accepts or rejects a request to join a group
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct RespondGroupJoinRequest {
+	/// The ID of the group
+	pub group_id: crate::id::Group,
+	/// The ID of the user who requested to join
+	pub user_id: crate::id::User,
+	/// How to respond to the request
+	pub action: GroupJoinRequestAction,
+	/// If to also block the user from requesting again when rejecting
+	pub block: bool,
+}
+
+impl Queryable<Authentication, ()> for RespondGroupJoinRequest {
+	fn url(&self, _: &Authentication) -> String {
+		format!(
+			"{}/groups/{}/requests/{}",
+			crate::API_BASE_URI,
+			self.group_id.as_ref(),
+			self.user_id.as_ref()
+		)
+	}
+
+	fn body(
+		&self, _state: &Authentication,
+	) -> Option<serde_json::Result<Vec<u8>>> {
+		Some(serde_json::to_vec(&serde_json::json!({
+			"action": self.action,
+			"block": self.block,
+		})))
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Put
+	}
+
+	fn deserialize(&self, _data: &[u8]) -> serde_json::Result<()> { Ok(()) }
+}
+
+/// Lists the roles of a group
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct ListGroupRoles {
+	/// The ID of the group
+	pub id: crate::id::Group,
+}
+
+impl Queryable<Authentication, Vec<crate::model::GroupRole>>
+	for ListGroupRoles
+{
+	fn url(&self, _: &Authentication) -> String {
+		format!("{}/groups/{}/roles", crate::API_BASE_URI, self.id.as_ref())
+	}
+}
+
+/// Creates a new role in a group
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct CreateGroupRole {
+	/// The ID of the group
+	pub group_id: crate::id::Group,
+	/// The name of the role
+	pub name: String,
+	/// The description of the role
+	pub description: String,
+	/// If members can give themselves the role
+	pub is_self_assignable: bool,
+	/// The permissions that the role grants
+	pub permissions: BTreeSet<GroupPermission>,
+}
+
+impl Queryable<Authentication, crate::model::GroupRole> for CreateGroupRole {
+	fn url(&self, _: &Authentication) -> String {
+		format!("{}/groups/{}/roles", crate::API_BASE_URI, self.group_id.as_ref())
+	}
+
+	fn body(
+		&self, _state: &Authentication,
+	) -> Option<serde_json::Result<Vec<u8>>> {
+		Some(serde_json::to_vec(&serde_json::json!({
+			"name": self.name,
+			"description": self.description,
+			"isSelfAssignable": self.is_self_assignable,
+			"permissions": self.permissions,
+		})))
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Post
+	}
+}
+
+/// The changes to make to a group role, unset fields are left as is
+#[derive(
+	Default, Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupRoleUpdate {
+	/// The name of the role
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
+	/// The description of the role
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub description: Option<String>,
+	/// If members can give themselves the role
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub is_self_assignable: Option<bool>,
+	/// The permissions that the role grants
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub permissions: Option<BTreeSet<GroupPermission>>,
+	/// The ordering of the role
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub order: Option<u32>,
+}
+
+/// Updates a role of a group, returning all the roles of the group
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct UpdateGroupRole {
+	/// The ID of the group
+	pub group_id: crate::id::Group,
+	/// The ID of the role to update
+	pub role_id: crate::id::GroupRole,
+	/// The changes to make
+	pub update: GroupRoleUpdate,
+}
+
+impl Queryable<Authentication, Vec<crate::model::GroupRole>>
+	for UpdateGroupRole
+{
+	fn url(&self, _: &Authentication) -> String {
+		format!(
+			"{}/groups/{}/roles/{}",
+			crate::API_BASE_URI,
+			self.group_id.as_ref(),
+			self.role_id.as_ref()
+		)
+	}
+
+	fn body(
+		&self, _state: &Authentication,
+	) -> Option<serde_json::Result<Vec<u8>>> {
+		Some(serde_json::to_vec(&self.update))
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Put
+	}
+}
+
+/// Deletes a role from a group, returning the remaining roles of the group
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct DeleteGroupRole {
+	/// The ID of the group
+	pub group_id: crate::id::Group,
+	/// The ID of the role to delete
+	pub role_id: crate::id::GroupRole,
+}
+
+impl Queryable<Authentication, Vec<crate::model::GroupRole>>
+	for DeleteGroupRole
+{
+	fn url(&self, _: &Authentication) -> String {
+		format!(
+			"{}/groups/{}/roles/{}",
+			crate::API_BASE_URI,
+			self.group_id.as_ref(),
+			self.role_id.as_ref()
+		)
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Delete
+	}
+}
+
+/// Gives a role to a group member, returning the member's roles
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct AddRoleToMember {
+	/// The ID of the group
+	pub group_id: crate::id::Group,
+	/// The ID of the user to give the role to
+	pub user_id: crate::id::User,
+	/// The ID of the role to give
+	pub role_id: crate::id::GroupRole,
+}
+
+impl Queryable<Authentication, Vec<crate::id::GroupRole>> for AddRoleToMember {
+	fn url(&self, _: &Authentication) -> String {
+		format!(
+			"{}/groups/{}/members/{}/roles/{}",
+			crate::API_BASE_URI,
+			self.group_id.as_ref(),
+			self.user_id.as_ref(),
+			self.role_id.as_ref()
+		)
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Put
+	}
+}
+
+/// Takes a role away from a group member, returning the member's roles
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct RemoveRoleFromMember {
+	/// The ID of the group
+	pub group_id: crate::id::Group,
+	/// The ID of the user to take the role away from
+	pub user_id: crate::id::User,
+	/// The ID of the role to take away
+	pub role_id: crate::id::GroupRole,
+}
+
+impl Queryable<Authentication, Vec<crate::id::GroupRole>>
+	for RemoveRoleFromMember
+{
+	fn url(&self, _: &Authentication) -> String {
+		format!(
+			"{}/groups/{}/members/{}/roles/{}",
+			crate::API_BASE_URI,
+			self.group_id.as_ref(),
+			self.user_id.as_ref(),
+			self.role_id.as_ref()
+		)
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Delete
+	}
+}
+
+/// Lists the posts of a group, newest first
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct ListGroupPosts {
+	/// The ID of the group
+	pub id: crate::id::Group,
+	/// If to only list the posts that are visible to everyone
+	pub public_only: bool,
+	/// Limits how many results are returned
+	#[serde(flatten)]
+	pub pagination: Pagination,
+}
+
+impl Queryable<Authentication, crate::model::GroupPosts> for ListGroupPosts {
+	fn url(&self, _: &Authentication) -> String {
+		format!(
+			"{}/groups/{}/posts?{}&publicOnly={}",
+			crate::API_BASE_URI,
+			self.id.as_ref(),
+			self.pagination.to_query_str(),
+			self.public_only
+		)
+	}
+}
+
+/// Creates a new post in a group
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct CreateGroupPost {
+	/// The ID of the group
+	pub group_id: crate::id::Group,
+	/// The title of the post
+	pub title: String,
+	/// The contents of the post
+	pub text: String,
+	/// Who can see the post
+	pub visibility: GroupPostVisibility,
+	/// Roles that can see the post, all members can if empty
+	pub role_ids: Vec<crate::id::GroupRole>,
+	/// The ID of an already uploaded image file to attach to the post
+	pub image_id: Option<String>,
+	/// If to notify the members about the post
+	pub send_notification: bool,
+}
+
+impl Queryable<Authentication, crate::model::GroupPost> for CreateGroupPost {
+	fn url(&self, _: &Authentication) -> String {
+		format!("{}/groups/{}/posts", crate::API_BASE_URI, self.group_id.as_ref())
+	}
+
+	fn body(
+		&self, _state: &Authentication,
+	) -> Option<serde_json::Result<Vec<u8>>> {
+		Some(serde_json::to_vec(&serde_json::json!({
+			"title": self.title,
+			"text": self.text,
+			"visibility": self.visibility,
+			"roleIds": self.role_ids,
+			"imageId": self.image_id,
+			"sendNotification": self.send_notification,
+		})))
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Post
+	}
+}
+
+/// The changes to make to a group post, unset fields are left as is
+#[derive(
+	Default, Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupPostUpdate {
+	/// The title of the post
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub title: Option<String>,
+	/// The contents of the post
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub text: Option<String>,
+	/// Who can see the post
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub visibility: Option<GroupPostVisibility>,
+	/// Roles that can see the post, all members can if empty
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub role_ids: Option<Vec<crate::id::GroupRole>>,
+	/// The ID of an already uploaded image file to attach to the post
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub image_id: Option<String>,
+	/// If to notify the members about the changes
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub send_notification: Option<bool>,
+}
+
+/// Updates a post of a group
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct UpdateGroupPost {
+	/// The ID of the group
+	pub group_id: crate::id::Group,
+	/// The ID of the post to update
+	pub post_id: crate::id::GroupPost,
+	/// The changes to make
+	pub update: GroupPostUpdate,
+}
+
+impl Queryable<Authentication, crate::model::GroupPost> for UpdateGroupPost {
+	fn url(&self, _: &Authentication) -> String {
+		format!(
+			"{}/groups/{}/posts/{}",
+			crate::API_BASE_URI,
+			self.group_id.as_ref(),
+			self.post_id.as_ref()
+		)
+	}
+
+	fn body(
+		&self, _state: &Authentication,
+	) -> Option<serde_json::Result<Vec<u8>>> {
+		Some(serde_json::to_vec(&self.update))
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Put
+	}
+}
+
+/// Deletes a post of a group
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct DeleteGroupPost {
+	/// The ID of the group
+	pub group_id: crate::id::Group,
+	/// The ID of the post to delete
+	pub post_id: crate::id::GroupPost,
+}
+
+impl Queryable<Authentication, ()> for DeleteGroupPost {
+	fn url(&self, _: &Authentication) -> String {
+		format!(
+			"{}/groups/{}/posts/{}",
+			crate::API_BASE_URI,
+			self.group_id.as_ref(),
+			self.post_id.as_ref()
+		)
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Delete
+	}
+
+	fn deserialize(&self, _data: &[u8]) -> serde_json::Result<()> { Ok(()) }
+}
+
+/// Lists the live instances of a group that the current user can see
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct ListGroupInstances {
+	/// The ID of the group
+	pub id: crate::id::Group,
+}
+
+impl Queryable<Authentication, Vec<crate::model::GroupInstance>>
+	for ListGroupInstances
+{
+	fn url(&self, _: &Authentication) -> String {
+		format!("{}/groups/{}/instances", crate::API_BASE_URI, self.id.as_ref())
+	}
+}
+
+/// Creates a new gallery in a group
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct CreateGroupGallery {
+	/// The ID of the group
+	pub group_id: crate::id::Group,
+	/// The name of the gallery
+	pub name: String,
+	/// The description of the gallery
+	pub description: String,
+	/// If only members of the group can see the gallery
+	pub members_only: bool,
+	/// Roles that can view the gallery, everyone can if `None`
+	pub role_ids_to_view: Option<Vec<crate::id::GroupRole>>,
+	/// Roles that can submit images to the gallery, everyone can if `None`
+	pub role_ids_to_submit: Option<Vec<crate::id::GroupRole>>,
+	/// Roles whose submissions are approved automatically
+	pub role_ids_to_auto_approve: Option<Vec<crate::id::GroupRole>>,
+	/// Roles that can manage the gallery
+	pub role_ids_to_manage: Option<Vec<crate::id::GroupRole>>,
+}
+
+impl Queryable<Authentication, crate::model::GroupGallery>
+	for CreateGroupGallery
+{
+	fn url(&self, _: &Authentication) -> String {
+		format!(
+			"{}/groups/{}/galleries",
+			crate::API_BASE_URI,
+			self.group_id.as_ref()
+		)
+	}
+
+	fn body(
+		&self, _state: &Authentication,
+	) -> Option<serde_json::Result<Vec<u8>>> {
+		Some(serde_json::to_vec(&serde_json::json!({
+			"name": self.name,
+			"description": self.description,
+			"membersOnly": self.members_only,
+			"roleIdsToView": self.role_ids_to_view,
+			"roleIdsToSubmit": self.role_ids_to_submit,
+			"roleIdsToAutoApprove": self.role_ids_to_auto_approve,
+			"roleIdsToManage": self.role_ids_to_manage,
+		})))
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Post
+	}
+}
+
+/// The changes to make to a group gallery, unset fields are left as is
+#[derive(
+	Default, Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupGalleryUpdate {
+	/// The name of the gallery
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
+	/// The description of the gallery
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub description: Option<String>,
+	/// If only members of the group can see the gallery
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub members_only: Option<bool>,
+	/// Roles that can view the gallery
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub role_ids_to_view: Option<Vec<crate::id::GroupRole>>,
+	/// Roles that can submit images to the gallery
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub role_ids_to_submit: Option<Vec<crate::id::GroupRole>>,
+	/// Roles whose submissions are approved automatically
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub role_ids_to_auto_approve: Option<Vec<crate::id::GroupRole>>,
+	/// Roles that can manage the gallery
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub role_ids_to_manage: Option<Vec<crate::id::GroupRole>>,
+}
+
+/// Updates a gallery of a group
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct UpdateGroupGallery {
+	/// The ID of the group
+	pub group_id: crate::id::Group,
+	/// The ID of the gallery to update
+	pub gallery_id: crate::id::GroupGallery,
+	/// The changes to make
+	pub update: GroupGalleryUpdate,
+}
+
+impl Queryable<Authentication, crate::model::GroupGallery>
+	for UpdateGroupGallery
+{
+	fn url(&self, _: &Authentication) -> String {
+		format!(
+			"{}/groups/{}/galleries/{}",
+			crate::API_BASE_URI,
+			self.group_id.as_ref(),
+			self.gallery_id.as_ref()
+		)
+	}
+
+	fn body(
+		&self, _state: &Authentication,
+	) -> Option<serde_json::Result<Vec<u8>>> {
+		Some(serde_json::to_vec(&self.update))
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Put
+	}
+}
+
+/// Lists the images that have been submitted to a group gallery
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct ListGroupGalleryImages {
+	/// The ID of the group
+	pub group_id: crate::id::Group,
+	/// The ID of the gallery
+	pub gallery_id: crate::id::GroupGallery,
+	/// Only list the approved (`true`) or pending (`false`) images,
+	/// or all of them if `None`
+	pub approved: Option<bool>,
+	/// Limits how many results are returned
+	#[serde(flatten)]
+	pub pagination: Pagination,
+}
+
+impl Queryable<Authentication, Vec<crate::model::GroupGalleryImage>>
+	for ListGroupGalleryImages
+{
+	fn url(&self, _: &Authentication) -> String {
+		let mut query = format!(
+			"{}/groups/{}/galleries/{}?{}",
+			crate::API_BASE_URI,
+			self.group_id.as_ref(),
+			self.gallery_id.as_ref(),
+			self.pagination.to_query_str()
+		);
+
+		if let Some(approved) = &self.approved {
+			query.push_str("&approved=");
+			query.push_str(&approved.to_string());
+		}
+
+		query
+	}
+}
+
+/// Approves a pending image in a group gallery
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct ApproveGroupGalleryImage {
+	/// The ID of the group
+	pub group_id: crate::id::Group,
+	/// The ID of the gallery
+	pub gallery_id: crate::id::GroupGallery,
+	/// The ID of the image to approve
+	pub image_id: crate::id::GroupGalleryImage,
+}
+
+impl Queryable<Authentication, crate::model::GroupGalleryImage>
+	for ApproveGroupGalleryImage
+{
+	fn url(&self, _: &Authentication) -> String {
+		format!(
+			"{}/groups/{}/galleries/{}/images/{}",
+			crate::API_BASE_URI,
+			self.group_id.as_ref(),
+			self.gallery_id.as_ref(),
+			self.image_id.as_ref()
+		)
+	}
+
+	fn body(
+		&self, _state: &Authentication,
+	) -> Option<serde_json::Result<Vec<u8>>> {
+		Some(serde_json::to_vec(&serde_json::json!({ "approved": true })))
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Put
+	}
+}
+
+/// Deletes an image from a group gallery, which also rejects pending images
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct DeleteGroupGalleryImage {
+	/// The ID of the group
+	pub group_id: crate::id::Group,
+	/// The ID of the gallery
+	pub gallery_id: crate::id::GroupGallery,
+	/// The ID of the image to delete
+	pub image_id: crate::id::GroupGalleryImage,
+}
+
+impl Queryable<Authentication, ()> for DeleteGroupGalleryImage {
+	fn url(&self, _: &Authentication) -> String {
+		format!(
+			"{}/groups/{}/galleries/{}/images/{}",
+			crate::API_BASE_URI,
+			self.group_id.as_ref(),
+			self.gallery_id.as_ref(),
+			self.image_id.as_ref()
+		)
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Delete
+	}
+
+	fn deserialize(&self, _data: &[u8]) -> serde_json::Result<()> { Ok(()) }
+}
+
+/// Invites a user to a group
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct InviteUserToGroup {
+	/// The ID of the group
+	pub group_id: crate::id::Group,
+	/// The ID of the user to invite
+	pub user_id: crate::id::User,
+	/// If to invite the user even if they've blocked the group
+	pub confirm_override_block: bool,
+}
+
+impl Queryable<Authentication, ()> for InviteUserToGroup {
+	fn url(&self, _: &Authentication) -> String {
+		format!("{}/groups/{}/invites", crate::API_BASE_URI, self.group_id.as_ref())
+	}
+
+	fn body(
+		&self, _state: &Authentication,
+	) -> Option<serde_json::Result<Vec<u8>>> {
+		Some(serde_json::to_vec(&serde_json::json!({
+			"userId": self.user_id,
+			"confirmOverrideBlock": self.confirm_override_block,
+		})))
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Post
+	}
+
+	fn deserialize(&self, _data: &[u8]) -> serde_json::Result<()> { Ok(()) }
+}
+
+/// Lists the users that have been invited to a group
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct ListGroupInvites {
+	/// The ID of the group
+	pub id: crate::id::Group,
+	/// Limits how many results are returned
+	#[serde(flatten)]
+	pub pagination: Pagination,
+}
+
+impl Queryable<Authentication, Vec<crate::model::FullGroupMember>>
+	for ListGroupInvites
+{
+	fn url(&self, _: &Authentication) -> String {
+		format!(
+			"{}/groups/{}/invites?{}",
+			crate::API_BASE_URI,
+			self.id.as_ref(),
+			self.pagination.to_query_str()
+		)
+	}
+}
+
+/// Cancels a pending invite to a group
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct CancelGroupInvite {
+	/// The ID of the group
+	pub group_id: crate::id::Group,
+	/// The ID of the invited user
+	pub user_id: crate::id::User,
+}
+
+impl Queryable<Authentication, ()> for CancelGroupInvite {
+	fn url(&self, _: &Authentication) -> String {
+		format!(
+			"{}/groups/{}/invites/{}",
+			crate::API_BASE_URI,
+			self.group_id.as_ref(),
+			self.user_id.as_ref()
+		)
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Delete
+	}
+
+	fn deserialize(&self, _data: &[u8]) -> serde_json::Result<()> { Ok(()) }
+}
+
+/// Searches for groups by text
+#[derive(
+	Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize,
+)]
+pub struct SearchGroups {
+	/// What to search for in the names and short codes of the groups
+	pub query: String,
+	/// Limits how many results are returned
+	#[serde(flatten)]
+	pub pagination: Pagination,
+}
+
+impl Queryable<Authentication, Vec<crate::model::LimitedGroup>>
+	for SearchGroups
+{
+	fn url(&self, _: &Authentication) -> String {
+		format!(
+			"{}/groups?query={}&{}",
+			crate::API_BASE_URI,
+			url::form_urlencoded::byte_serialize(self.query.as_bytes())
+				.collect::<String>(),
+			self.pagination.to_query_str()
+		)
+	}
+}
+
+/// Finds a group by its short code and discriminator, like `ABCD.1234`.
+///
+/// Only looks through the first 100 search results, use
+/// [`AuthenticatedVRC::find_group_by_code`](crate::api_client::AuthenticatedVRC::find_group_by_code)
+/// to look through all of them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct GroupByCode {
+	/// The code of the group to find
+	pub code: GroupCode,
+}
+
+impl Queryable<Authentication, Option<crate::model::LimitedGroup>>
+	for GroupByCode
+{
+	fn url(&self, _: &Authentication) -> String {
+		format!(
+			"{}/groups?query={}&n=100",
+			crate::API_BASE_URI,
+			url::form_urlencoded::byte_serialize(self.code.to_string().as_bytes())
+				.collect::<String>(),
+		)
+	}
+
+	fn deserialize(
+		&self, data: &[u8],
+	) -> serde_json::Result<Option<crate::model::LimitedGroup>> {
+		let groups: Vec<crate::model::LimitedGroup> = serde_json::from_slice(data)?;
+		Ok(self.code.find(groups))
+	}
+}
+
+/// Sets if the current user is representing a group
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct SetRepresentedGroup {
+	/// The ID of the group
+	pub id: crate::id::Group,
+	/// If to represent the group, or to stop representing it
+	pub is_representing: bool,
+}
+
+impl Queryable<Authentication, ()> for SetRepresentedGroup {
+	fn url(&self, _: &Authentication) -> String {
+		format!(
+			"{}/groups/{}/representation",
+			crate::API_BASE_URI,
+			self.id.as_ref()
+		)
+	}
+
+	fn body(
+		&self, _state: &Authentication,
+	) -> Option<serde_json::Result<Vec<u8>>> {
+		Some(serde_json::to_vec(&serde_json::json!({
+			"isRepresenting": self.is_representing,
+		})))
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Put
+	}
+
+	fn deserialize(&self, _data: &[u8]) -> serde_json::Result<()> { Ok(()) }
+}
+
+#[cfg(test)]
+#[test]
+fn group_search_urls() {
+	let auth = Authentication { token: String::new(), second_factor_token: None };
+
+	let query = SearchGroups {
+		query: "a b&c#d".to_owned(),
+		pagination: Pagination::default(),
+	};
+	assert!(query.url(&auth).contains("?query=a+b%26c%23d&n=10&"));
+
+	let query = GroupByCode { code: "ABCD.0011".parse().unwrap() };
+	assert!(query.url(&auth).contains("&n=100"));
+	let groups: Vec<_> = (1..=11)
+		.map(|i| {
+			serde_json::json!({
+				"id": format!("grp_93451756-8327-4ecc-b978-3e60aa9f64{i:02}"),
+				"name": "Fuzzy match",
+				"shortCode": "ABCD",
+				"discriminator": format!("{i:04}"),
+				"ownerId": "usr_c1644b5b-3ca4-45b4-97c6-a2a0de70d469",
+				"memberCount": 1,
+			})
+		})
+		.collect();
+	let group = query
+		.deserialize(&serde_json::to_vec(&groups).unwrap())
+		.unwrap()
+		.expect("the group past the first 10 results to be found");
+	assert_eq!(group.discriminator, "0011");
+}
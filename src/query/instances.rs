@@ -2,6 +2,7 @@ use racal::Queryable;
 use serde::{Deserialize, Serialize};
 
 use super::Authentication;
+use crate::model::{GroupAccessType, InstancePrivacy, InstanceRegion};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
 /// Also works as the login request
@@ -15,3 +16,49 @@ impl Queryable<Authentication, crate::model::Instance> for Instance {
 		format!("{}/instances/{}", crate::API_BASE_URI, self.id)
 	}
 }
+
+/// Creates a new instance that is owned by a group
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct CreateGroupInstance {
+	/// The ID of the group to own the instance
+	pub group_id: crate::id::Group,
+	/// The ID of the world to create the instance of
+	pub world_id: crate::id::World,
+	/// The region to host the instance in
+	pub region: InstanceRegion,
+	/// Who can join the instance
+	pub group_access_type: GroupAccessType,
+	/// Roles that can join the instance, all members can if empty.
+	///
+	/// Only applies to [`GroupAccessType::Members`] instances.
+	pub role_ids: Vec<crate::id::GroupRole>,
+	/// If users join a queue when the instance is full
+	pub queue_enabled: bool,
+	/// If only age verified users can join the instance
+	pub age_gate: bool,
+}
+
+impl Queryable<Authentication, crate::model::Instance> for CreateGroupInstance {
+	fn url(&self, _: &Authentication) -> String {
+		format!("{}/instances", crate::API_BASE_URI)
+	}
+
+	fn body(
+		&self, _state: &Authentication,
+	) -> Option<serde_json::Result<Vec<u8>>> {
+		Some(serde_json::to_vec(&serde_json::json!({
+			"type": InstancePrivacy::Group,
+			"ownerId": self.group_id,
+			"worldId": self.world_id,
+			"region": self.region,
+			"groupAccessType": self.group_access_type,
+			"roleIds": self.role_ids,
+			"queueEnabled": self.queue_enabled,
+			"ageGate": self.age_gate,
+		})))
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Post
+	}
+}
@@ -7,6 +7,8 @@ use strum::AsRefStr;
 
 mod auth;
 pub use auth::*;
+mod avatars;
+pub use avatars::*;
 mod friends;
 pub use friends::*;
 mod groups;
@@ -58,10 +60,13 @@ impl std::fmt::Debug for Authentication {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("Authentication")
 			.field("token", &"*****")
-			.field("second_factor_token", match &self.second_factor_token {
-				Some(_) => &"Some(*****)",
-				None => &"None",
-			})
+			.field(
+				"second_factor_token",
+				match &self.second_factor_token {
+					Some(_) => &"Some(*****)",
+					None => &"None",
+				},
+			)
 			.finish()
 	}
 }
@@ -72,21 +77,27 @@ impl racal::FromApiState<Self> for Authentication {
 
 /// Ordering for a query
 #[derive(
-	Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, AsRefStr,
+	Debug,
+	Default,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Hash,
+	Serialize,
+	Deserialize,
+	AsRefStr,
 )]
 #[serde(rename_all = "camelCase")]
 #[strum(serialize_all = "camelCase")]
 pub enum Order {
 	/// Ascending ordering, so first is first
+	#[default]
 	Ascending,
 	/// Descending ordering, so last is first
 	Descending,
 }
 
-impl Default for Order {
-	fn default() -> Self { Self::Ascending }
-}
-
 /// Gets the friends list
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub struct Pagination {
@@ -16,6 +16,21 @@ impl Queryable<Authentication, crate::model::AnyUser> for User {
 	}
 }
 
+/// Lists the groups that a user is a member of
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct ListUserGroups {
+	/// The ID of the user
+	pub id: crate::id::User,
+}
+
+impl Queryable<Authentication, Vec<crate::model::UserGroup>>
+	for ListUserGroups
+{
+	fn url(&self, _: &Authentication) -> String {
+		format!("{}/users/{}/groups", crate::API_BASE_URI, self.id.as_ref())
+	}
+}
+
 /// Search and list any users by text query
 #[derive(
 	Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize,
@@ -39,3 +54,128 @@ impl Queryable<Authentication, Vec<crate::model::AccountData>> for SearchUser {
 		)
 	}
 }
+
+/// The changes to make to the current user, unset fields are left as is
+#[derive(
+	Default, Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrentUserUpdate {
+	/// The status of the user
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub status: Option<crate::model::UserStatus>,
+	/// The custom status text of the user
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub status_description: Option<String>,
+	/// Text that the user has written about themselves
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub bio: Option<String>,
+	/// Links that the user has added about themselves
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub bio_links: Option<Vec<String>>,
+	/// The pronouns of the user
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub pronouns: Option<String>,
+	/// URL to the user's icon, an empty string removes it
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub user_icon: Option<String>,
+	/// URL to the user's profile picture, an empty string removes it
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub profile_pic_override: Option<String>,
+	/// The tags of the user, including the `language_` tags.
+	///
+	/// Replaces all the tags that the user can change.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub tags: Option<Vec<String>>,
+	/// The world that the user spawns in
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub home_location: Option<crate::id::World>,
+}
+
+/// Updates the current user
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct UpdateCurrentUser {
+	/// The ID of the current user
+	pub id: crate::id::User,
+	/// The changes to make
+	pub update: CurrentUserUpdate,
+}
+
+impl Queryable<Authentication, crate::model::CurrentAccount>
+	for UpdateCurrentUser
+{
+	fn url(&self, _: &Authentication) -> String {
+		format!("{}/users/{}", crate::API_BASE_URI, self.id.as_ref())
+	}
+
+	fn body(
+		&self, _state: &Authentication,
+	) -> Option<serde_json::Result<Vec<u8>>> {
+		Some(serde_json::to_vec(&self.update))
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Put
+	}
+}
+
+/// Lists the notes that the current user has written about other users
+#[derive(
+	Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize,
+)]
+pub struct ListUserNotes {
+	/// Limits how many results are returned
+	#[serde(flatten)]
+	pub pagination: Pagination,
+}
+
+impl Queryable<Authentication, Vec<crate::model::UserNote>> for ListUserNotes {
+	fn url(&self, _: &Authentication) -> String {
+		format!(
+			"{}/userNotes?{}",
+			crate::API_BASE_URI,
+			self.pagination.to_query_str()
+		)
+	}
+}
+
+/// Gets a specific note about a user
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct GetUserNote {
+	/// The ID of the note
+	pub id: crate::id::UserNote,
+}
+
+impl Queryable<Authentication, crate::model::UserNote> for GetUserNote {
+	fn url(&self, _: &Authentication) -> String {
+		format!("{}/userNotes/{}", crate::API_BASE_URI, self.id.as_ref())
+	}
+}
+
+/// Sets the note about a user, an empty note clears it
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct UpdateUserNote {
+	/// The ID of the user that the note is about
+	pub target_user_id: crate::id::User,
+	/// The note itself
+	pub note: String,
+}
+
+impl Queryable<Authentication, crate::model::UserNote> for UpdateUserNote {
+	fn url(&self, _: &Authentication) -> String {
+		format!("{}/userNotes", crate::API_BASE_URI)
+	}
+
+	fn body(
+		&self, _state: &Authentication,
+	) -> Option<serde_json::Result<Vec<u8>>> {
+		Some(serde_json::to_vec(&serde_json::json!({
+			"targetUserId": self.target_user_id,
+			"note": self.note,
+		})))
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Post
+	}
+}
@@ -3,17 +3,27 @@ use serde::{Deserialize, Serialize};
 use strum::AsRefStr;
 
 use super::{Authentication, Order, Pagination};
-use crate::model::ReleaseStatus;
+use crate::model::{ReleaseStatus, UnityVersion, WorldTag};
 
 /// The sorting for a world search
 #[derive(
-	Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize, AsRefStr,
+	Debug,
+	Default,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Hash,
+	Deserialize,
+	Serialize,
+	AsRefStr,
 )]
 #[serde(rename_all = "camelCase")]
 #[allow(missing_docs)]
 #[strum(serialize_all = "camelCase")]
 pub enum WorldsSort {
 	Popularity,
+	#[default]
 	Heat,
 	Trust,
 	Shuffle,
@@ -37,51 +47,47 @@ pub enum WorldsSort {
 	Name,
 }
 
-impl Default for WorldsSort {
-	fn default() -> Self { Self::Heat }
-}
-
 #[derive(
 	Default, Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize,
 )]
-/// Lists active worlds based on the  provided criteria
-pub struct ActiveWorlds {
+/// The sorting and filters shared by the world listing queries
+pub struct WorldsFilter {
 	/// If to filter based on featured status
 	pub featured: Option<bool>,
-	/// The sorting of the search
-	pub sort: WorldsSort,
-	/// The ordering of the search
-	pub order: Order,
+	/// The sorting of the search, left to the endpoint if `None`
+	pub sort: Option<WorldsSort>,
+	/// The ordering of the search, left to the endpoint if `None`
+	pub order: Option<Order>,
 	/// If to filter based on a search string
 	pub search: Option<String>,
-	/// If to filter based on a search tag
-	pub tag: Option<String>,
-	/// If to exclude results based on a search tag
-	pub no_tag: Option<String>,
+	/// Only include worlds that have these tags
+	pub tag: Vec<WorldTag>,
+	/// Exclude worlds that have any of these tags
+	pub no_tag: Vec<WorldTag>,
 	/// If to filter based on the release status
 	pub release_status: Option<ReleaseStatus>,
 	/// If to filter based on the max unity version
-	pub max_unity_version: Option<String>,
+	pub max_unity_version: Option<UnityVersion>,
 	/// If to filter based on the min unity version
-	pub min_unity_version: Option<String>,
-	/// `standalonewindows` or `android` for example
-	pub platform: Option<String>,
-	/// The pagination for the query
-	#[serde(flatten)]
-	pub pagination: Pagination,
+	pub min_unity_version: Option<UnityVersion>,
+	/// If to filter based on the supported platform
+	pub platform: Option<crate::model::Platform>,
 }
 
-impl Queryable<Authentication, Vec<crate::model::WorldListing>>
-	for ActiveWorlds
-{
-	fn url(&self, _: &Authentication) -> String {
-		let mut query = format!(
-			"{}/worlds/active?{}&sort={}&order={}",
-			crate::API_BASE_URI,
-			self.pagination.to_query_str(),
-			&self.sort.as_ref(),
-			&self.order.as_ref(),
-		);
+impl WorldsFilter {
+	/// The set parameters, each prefixed with `&`
+	fn to_query_str(&self) -> String {
+		let mut query = String::new();
+
+		if let Some(sort) = &self.sort {
+			query.push_str("&sort=");
+			query.push_str(sort.as_ref());
+		}
+
+		if let Some(order) = &self.order {
+			query.push_str("&order=");
+			query.push_str(order.as_ref());
+		}
 
 		if let Some(release_status) = &self.release_status {
 			query.push_str("&releaseStatus=");
@@ -95,36 +101,180 @@ impl Queryable<Authentication, Vec<crate::model::WorldListing>>
 
 		if let Some(search) = &self.search {
 			query.push_str("&search=");
-			query.push_str(search);
+			query.extend(url::form_urlencoded::byte_serialize(search.as_bytes()));
 		}
-		if let Some(tag) = &self.tag {
+		if !self.tag.is_empty() {
 			query.push_str("&tag=");
-			query.push_str(tag);
+			query.push_str(&join_tags(&self.tag));
 		}
-		if let Some(no_tag) = &self.no_tag {
+		if !self.no_tag.is_empty() {
 			query.push_str("&notag=");
-			query.push_str(no_tag);
+			query.push_str(&join_tags(&self.no_tag));
 		}
 
 		if let Some(max_unity_version) = &self.max_unity_version {
 			query.push_str("&maxUnityVersion=");
-			query.push_str(max_unity_version);
+			query.push_str(&max_unity_version.to_string());
 		}
 
 		if let Some(min_unity_version) = &self.min_unity_version {
 			query.push_str("&minUnityVersion=");
-			query.push_str(min_unity_version);
+			query.push_str(&min_unity_version.to_string());
 		}
 
 		if let Some(platform) = &self.platform {
 			query.push_str("&platform=");
-			query.push_str(platform);
+			query.push_str(platform.as_ref());
+		}
+
+		query
+	}
+}
+
+#[derive(
+	Default, Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize,
+)]
+/// Lists active worlds based on the  provided criteria
+pub struct ActiveWorlds {
+	/// The sorting and filters of the search
+	#[serde(flatten)]
+	pub filter: WorldsFilter,
+	/// The pagination for the query
+	#[serde(flatten)]
+	pub pagination: Pagination,
+}
+
+impl Queryable<Authentication, Vec<crate::model::WorldListing>>
+	for ActiveWorlds
+{
+	fn url(&self, _: &Authentication) -> String {
+		format!(
+			"{}/worlds/active?{}{}",
+			crate::API_BASE_URI,
+			self.pagination.to_query_str(),
+			self.filter.to_query_str(),
+		)
+	}
+}
+
+#[derive(
+	Default, Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize,
+)]
+/// Searches all the worlds that the current user can see
+pub struct SearchWorlds {
+	/// Only include the worlds of the current user
+	pub own_only: bool,
+	/// Only include the worlds made by the developer
+	pub developer_id: Option<crate::id::User>,
+	/// The sorting and filters of the search
+	#[serde(flatten)]
+	pub filter: WorldsFilter,
+	/// The pagination for the query
+	#[serde(flatten)]
+	pub pagination: Pagination,
+}
+
+impl Queryable<Authentication, Vec<crate::model::WorldListing>>
+	for SearchWorlds
+{
+	fn url(&self, _: &Authentication) -> String {
+		let mut query = format!(
+			"{}/worlds?{}{}",
+			crate::API_BASE_URI,
+			self.pagination.to_query_str(),
+			self.filter.to_query_str(),
+		);
+
+		if self.own_only {
+			query.push_str("&user=me");
+		}
+
+		if let Some(developer_id) = &self.developer_id {
+			query.push_str("&userId=");
+			query.push_str(developer_id.as_ref());
 		}
 
 		query
 	}
 }
 
+#[derive(
+	Default, Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize,
+)]
+/// Lists the worlds that the current user has recently visited
+pub struct RecentWorlds {
+	/// The sorting and filters of the search
+	#[serde(flatten)]
+	pub filter: WorldsFilter,
+	/// The pagination for the query
+	#[serde(flatten)]
+	pub pagination: Pagination,
+}
+
+impl Queryable<Authentication, Vec<crate::model::WorldListing>>
+	for RecentWorlds
+{
+	fn url(&self, _: &Authentication) -> String {
+		format!(
+			"{}/worlds/recent?{}{}",
+			crate::API_BASE_URI,
+			self.pagination.to_query_str(),
+			self.filter.to_query_str(),
+		)
+	}
+}
+
+#[derive(
+	Default, Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize,
+)]
+/// Lists the worlds that the current user has favorited
+pub struct FavoritedWorlds {
+	/// The sorting and filters of the search
+	#[serde(flatten)]
+	pub filter: WorldsFilter,
+	/// The pagination for the query
+	#[serde(flatten)]
+	pub pagination: Pagination,
+}
+
+impl Queryable<Authentication, Vec<crate::model::FavoritedWorld>>
+	for FavoritedWorlds
+{
+	fn url(&self, _: &Authentication) -> String {
+		format!(
+			"{}/worlds/favorites?{}{}",
+			crate::API_BASE_URI,
+			self.pagination.to_query_str(),
+			self.filter.to_query_str(),
+		)
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+/// Lists the worlds that a user has made
+pub struct OwnWorlds {
+	/// The ID of the user to list the worlds of
+	pub id: crate::id::User,
+	/// The sorting and filters of the search
+	#[serde(flatten)]
+	pub filter: WorldsFilter,
+	/// The pagination for the query
+	#[serde(flatten)]
+	pub pagination: Pagination,
+}
+
+impl Queryable<Authentication, Vec<crate::model::WorldListing>> for OwnWorlds {
+	fn url(&self, _: &Authentication) -> String {
+		format!(
+			"{}/worlds?userId={}&{}{}",
+			crate::API_BASE_URI,
+			self.id.as_ref(),
+			self.pagination.to_query_str(),
+			self.filter.to_query_str(),
+		)
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
 /// Also works as the login request
 pub struct World {
@@ -137,3 +287,179 @@ impl Queryable<Authentication, crate::model::World> for World {
 		format!("{}/worlds/{}", crate::API_BASE_URI, self.id.as_ref())
 	}
 }
+
+/// The changes to make to a world, unset fields are left as is
+#[derive(
+	Default, Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct WorldUpdate {
+	/// The name of the world
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
+	/// The description of the world
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub description: Option<String>,
+	/// How many players can fit into an instance of the world
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub capacity: Option<u16>,
+	/// How many players the world is recommended for
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub recommended_capacity: Option<u16>,
+	/// The tags of the world, replacing all the current ones
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub tags: Option<Vec<WorldTag>>,
+	/// A `YouTube` ID for a preview of the world
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub preview_youtube_id: Option<String>,
+	/// An image for displaying the world
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub image_url: Option<url::Url>,
+	/// The release status of the world
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub release_status: Option<ReleaseStatus>,
+}
+
+/// Updates a world of the current user
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct UpdateWorld {
+	/// The ID of the world to update
+	pub id: crate::id::World,
+	/// The changes to make
+	pub update: WorldUpdate,
+}
+
+impl Queryable<Authentication, crate::model::World> for UpdateWorld {
+	fn url(&self, _: &Authentication) -> String {
+		format!("{}/worlds/{}", crate::API_BASE_URI, self.id.as_ref())
+	}
+
+	fn body(
+		&self, _state: &Authentication,
+	) -> Option<serde_json::Result<Vec<u8>>> {
+		Some(serde_json::to_vec(&self.update))
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Put
+	}
+}
+
+/// Checks if a world of the current user can be published to Community Labs
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct WorldPublishStatus {
+	/// The ID of the world
+	pub id: crate::id::World,
+}
+
+impl Queryable<Authentication, crate::model::WorldPublishStatus>
+	for WorldPublishStatus
+{
+	fn url(&self, _: &Authentication) -> String {
+		format!("{}/worlds/{}/publish", crate::API_BASE_URI, self.id.as_ref())
+	}
+}
+
+/// Publishes a world of the current user to Community Labs.
+///
+/// Use [`UpdateWorld`] with the [`ReleaseStatus`] to change if the world is
+/// public or private.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct PublishWorld {
+	/// The ID of the world to publish
+	pub id: crate::id::World,
+}
+
+impl Queryable<Authentication, ()> for PublishWorld {
+	fn url(&self, _: &Authentication) -> String {
+		format!("{}/worlds/{}/publish", crate::API_BASE_URI, self.id.as_ref())
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Put
+	}
+
+	fn deserialize(&self, _data: &[u8]) -> serde_json::Result<()> { Ok(()) }
+}
+
+/// Unpublishes a world of the current user, making it private again
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct UnpublishWorld {
+	/// The ID of the world to unpublish
+	pub id: crate::id::World,
+}
+
+impl Queryable<Authentication, ()> for UnpublishWorld {
+	fn url(&self, _: &Authentication) -> String {
+		format!("{}/worlds/{}/publish", crate::API_BASE_URI, self.id.as_ref())
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Delete
+	}
+
+	fn deserialize(&self, _data: &[u8]) -> serde_json::Result<()> { Ok(()) }
+}
+
+/// Deletes a world of the current user
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct DeleteWorld {
+	/// The ID of the world to delete
+	pub id: crate::id::World,
+}
+
+impl Queryable<Authentication, ()> for DeleteWorld {
+	fn url(&self, _: &Authentication) -> String {
+		format!("{}/worlds/{}", crate::API_BASE_URI, self.id.as_ref())
+	}
+
+	fn method(&self, _state: &Authentication) -> racal::RequestMethod {
+		racal::RequestMethod::Delete
+	}
+
+	fn deserialize(&self, _data: &[u8]) -> serde_json::Result<()> { Ok(()) }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+/// Gets an instance of a world
+pub struct WorldInstance {
+	/// The world instance ID to get
+	pub id: crate::id::WorldInstance,
+}
+
+impl Queryable<Authentication, crate::model::Instance> for WorldInstance {
+	fn url(&self, _: &Authentication) -> String {
+		format!(
+			"{}/worlds/{}/{}",
+			crate::API_BASE_URI,
+			self.id.world.as_ref(),
+			self.id.instance.as_ref()
+		)
+	}
+}
+
+fn join_tags(tags: &[WorldTag]) -> String {
+	tags.iter().map(ToString::to_string).collect::<Vec<_>>().join(",")
+}
+
+#[cfg(test)]
+#[test]
+fn worlds_filter_query() {
+	let auth = Authentication { token: String::new(), second_factor_token: None };
+
+	let query = RecentWorlds::default();
+	assert_eq!(
+		query.url(&auth),
+		format!("{}/worlds/recent?n=10&offset=0", crate::API_BASE_URI)
+	);
+
+	let query = ActiveWorlds {
+		filter: WorldsFilter {
+			sort: Some(WorldsSort::Popularity),
+			search: Some("a b&c".to_owned()),
+			..WorldsFilter::default()
+		},
+		..ActiveWorlds::default()
+	};
+	assert!(query.url(&auth).ends_with("&sort=popularity&search=a+b%26c"));
+}
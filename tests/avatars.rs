@@ -0,0 +1,21 @@
+#![cfg(feature = "api_client")]
+
+use vrc::{
+	api_client::{ApiClient, ApiError},
+	model::Avatar,
+};
+
+mod common;
+
+#[tokio::test]
+#[ignore]
+async fn own_avatars() -> Result<(), ApiError> {
+	let api_client = common::api_client()?;
+
+	let query = vrc::query::ListOwnAvatars::default();
+	let own_avatars: Vec<Avatar> = api_client.query(query).await?;
+
+	dbg!(&own_avatars);
+
+	Ok(())
+}
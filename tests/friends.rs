@@ -25,3 +25,29 @@ async fn friends() -> Result<(), ApiError> {
 
 	Ok(())
 }
+
+#[tokio::test]
+#[ignore]
+async fn friend_status() -> Result<(), ApiError> {
+	let friend_id = match &common::TEST_CONFIG.friend_id {
+		Some(v) => v,
+		None => {
+			println!(
+				"Skipping test {} due to lack of friend id",
+				stringify!(friend_status)
+			);
+			return Ok(());
+		}
+	};
+
+	let api_client = common::api_client()?;
+
+	let query = vrc::query::FriendStatus { id: friend_id.clone() };
+	let status: vrc::model::FriendStatus = api_client.query(query).await?;
+
+	dbg!(&status);
+
+	assert!(status.is_friend);
+
+	Ok(())
+}
@@ -2,8 +2,17 @@
 
 use vrc::{
 	api_client::{ApiClient, ApiError},
-	model::{Group, GroupAuditLogs, GroupBan, GroupMember},
-	query::Pagination,
+	model::{
+		FullGroupMember,
+		Group,
+		GroupAuditLogs,
+		GroupInstance,
+		GroupPosts,
+		GroupRole,
+		LimitedGroup,
+		LimitedGroupMember,
+	},
+	query::{GroupMembersSort, Pagination},
 };
 
 mod common;
@@ -60,12 +69,12 @@ async fn group_member() -> Result<(), ApiError> {
 		user_id: user_id.clone(),
 		group_id: group_id.clone(),
 	};
-	let group_member: GroupMember =
+	let group_member: LimitedGroupMember =
 		api_client.query(query).await?.expect("Group to exist");
 
 	dbg!(&group_member);
 
-	assert_eq!(&group_member.user_id, user_id);
+	assert_eq!(&group_member.base.user_id, user_id);
 
 	Ok(())
 }
@@ -117,7 +126,7 @@ async fn group_bans() -> Result<(), ApiError> {
 		id: group_id.clone(),
 		pagination: Pagination::default(),
 	};
-	let group_bans: Vec<GroupBan> = api_client.query(query).await?;
+	let group_bans: Vec<FullGroupMember> = api_client.query(query).await?;
 
 	dbg!(&group_bans);
 
@@ -125,3 +134,169 @@ async fn group_bans() -> Result<(), ApiError> {
 
 	Ok(())
 }
+
+#[tokio::test]
+#[ignore]
+async fn group_members() -> Result<(), ApiError> {
+	let group_id = match &common::TEST_CONFIG.group_id {
+		Some(v) => v,
+		None => {
+			println!(
+				"Skipping test {} due to lack of group id",
+				stringify!(group_members)
+			);
+			return Ok(());
+		}
+	};
+
+	let api_client = common::api_client()?;
+
+	let query = vrc::query::ListGroupMembers {
+		id: group_id.clone(),
+		sort: GroupMembersSort::default(),
+		role_id: None,
+		pagination: Pagination::default(),
+	};
+	let group_members: Vec<FullGroupMember> = api_client.query(query).await?;
+
+	dbg!(&group_members);
+
+	assert!(!group_members.is_empty());
+
+	Ok(())
+}
+
+#[tokio::test]
+#[ignore]
+async fn group_roles() -> Result<(), ApiError> {
+	let group_id = match &common::TEST_CONFIG.group_id {
+		Some(v) => v,
+		None => {
+			println!(
+				"Skipping test {} due to lack of group id",
+				stringify!(group_roles)
+			);
+			return Ok(());
+		}
+	};
+
+	let api_client = common::api_client()?;
+
+	let query = vrc::query::ListGroupRoles { id: group_id.clone() };
+	let group_roles: Vec<GroupRole> = api_client.query(query).await?;
+
+	dbg!(&group_roles);
+
+	assert!(!group_roles.is_empty());
+
+	Ok(())
+}
+
+#[tokio::test]
+#[ignore]
+async fn group_posts() -> Result<(), ApiError> {
+	let group_id = match &common::TEST_CONFIG.group_id {
+		Some(v) => v,
+		None => {
+			println!(
+				"Skipping test {} due to lack of group id",
+				stringify!(group_posts)
+			);
+			return Ok(());
+		}
+	};
+
+	let api_client = common::api_client()?;
+
+	let query = vrc::query::ListGroupPosts {
+		id: group_id.clone(),
+		public_only: false,
+		pagination: Pagination { limit: 10, offset: 0 },
+	};
+	let group_posts: GroupPosts = api_client.query(query).await?;
+
+	dbg!(&group_posts);
+
+	Ok(())
+}
+
+#[tokio::test]
+#[ignore]
+async fn group_instances() -> Result<(), ApiError> {
+	let group_id = match &common::TEST_CONFIG.group_id {
+		Some(v) => v,
+		None => {
+			println!(
+				"Skipping test {} due to lack of group id",
+				stringify!(group_instances)
+			);
+			return Ok(());
+		}
+	};
+
+	let api_client = common::api_client()?;
+
+	let query = vrc::query::ListGroupInstances { id: group_id.clone() };
+	let group_instances: Vec<GroupInstance> = api_client.query(query).await?;
+
+	dbg!(&group_instances);
+
+	Ok(())
+}
+
+#[tokio::test]
+#[ignore]
+async fn group_invites() -> Result<(), ApiError> {
+	let group_id = match &common::TEST_CONFIG.group_id {
+		Some(v) => v,
+		None => {
+			println!(
+				"Skipping test {} due to lack of group id",
+				stringify!(group_invites)
+			);
+			return Ok(());
+		}
+	};
+
+	let api_client = common::api_client()?;
+
+	let query = vrc::query::ListGroupInvites {
+		id: group_id.clone(),
+		pagination: Pagination { limit: 10, offset: 0 },
+	};
+	let group_invites: Vec<FullGroupMember> = api_client.query(query).await?;
+
+	dbg!(&group_invites);
+
+	Ok(())
+}
+
+#[tokio::test]
+#[ignore]
+async fn group_by_code() -> Result<(), ApiError> {
+	let group_id = match &common::TEST_CONFIG.group_id {
+		Some(v) => v,
+		None => {
+			println!(
+				"Skipping test {} due to lack of group id",
+				stringify!(group_by_code)
+			);
+			return Ok(());
+		}
+	};
+
+	let api_client = common::api_client()?;
+
+	let query = vrc::query::Group { id: group_id.clone() };
+	let group: Group = api_client.query(query).await?;
+
+	let query = vrc::query::GroupByCode { code: group.code() };
+	let found_group: LimitedGroup =
+		api_client.query(query).await?.expect("Group to be found by its code");
+
+	dbg!(&found_group);
+
+	assert_eq!(&found_group.id, group_id);
+
+	Ok(())
+}
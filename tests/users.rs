@@ -130,3 +130,61 @@ async fn user_friend() -> Result<(), ApiError> {
 
 	Ok(())
 }
+
+#[tokio::test]
+#[ignore]
+async fn user_groups() -> Result<(), ApiError> {
+	let api_client = common::api_client()?;
+
+	let query = vrc::query::ListUserGroups {
+		id: "usr_c1644b5b-3ca4-45b4-97c6-a2a0de70d469".parse().unwrap(),
+	};
+	let groups: Vec<vrc::model::UserGroup> = api_client.query(query).await?;
+
+	dbg!(&groups);
+
+	Ok(())
+}
+
+#[tokio::test]
+#[ignore]
+async fn update_current_user() -> Result<(), ApiError> {
+	let self_id = match &common::TEST_CONFIG.self_id {
+		Some(v) => v,
+		None => {
+			println!(
+				"Skipping test {} due to lack of self id",
+				stringify!(update_current_user)
+			);
+			return Ok(());
+		}
+	};
+
+	let api_client = common::api_client()?;
+
+	// Doesn't actually change anything, as all the fields are left unset
+	let query = vrc::query::UpdateCurrentUser {
+		id: self_id.clone(),
+		update: vrc::query::CurrentUserUpdate::default(),
+	};
+	let user: vrc::model::CurrentAccount = api_client.query(query).await?;
+
+	dbg!(&user);
+
+	assert_eq!(&user.base.id, self_id);
+
+	Ok(())
+}
+
+#[tokio::test]
+#[ignore]
+async fn user_notes() -> Result<(), ApiError> {
+	let api_client = common::api_client()?;
+
+	let query = vrc::query::ListUserNotes::default();
+	let notes: Vec<vrc::model::UserNote> = api_client.query(query).await?;
+
+	dbg!(&notes);
+
+	Ok(())
+}
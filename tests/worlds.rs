@@ -2,7 +2,7 @@
 
 use vrc::{
 	api_client::{ApiClient, ApiError},
-	model::{World, WorldListing},
+	model::{FavoritedWorld, World, WorldListing},
 };
 
 mod common;
@@ -39,3 +39,29 @@ async fn world() -> Result<(), ApiError> {
 
 	Ok(())
 }
+
+#[tokio::test]
+#[ignore]
+async fn recent_worlds() -> Result<(), ApiError> {
+	let api_client = common::api_client()?;
+
+	let query = vrc::query::RecentWorlds::default();
+	let recent_worlds: Vec<WorldListing> = api_client.query(query).await?;
+
+	dbg!(&recent_worlds);
+
+	Ok(())
+}
+
+#[tokio::test]
+#[ignore]
+async fn favorited_worlds() -> Result<(), ApiError> {
+	let api_client = common::api_client()?;
+
+	let query = vrc::query::FavoritedWorlds::default();
+	let favorited_worlds: Vec<FavoritedWorld> = api_client.query(query).await?;
+
+	dbg!(&favorited_worlds);
+
+	Ok(())
+}